@@ -0,0 +1,153 @@
+use crate::matrices::Matrix4x4;
+use crate::types::RealScalar;
+use crate::vectors::{Vector, Vector3D};
+
+// which way the camera looks in view space, Right looks down -z (OpenGL), Left down +z (D3D)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Handedness {
+    Right,
+    Left,
+}
+
+// where near and far end up in normalized device coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DepthRange {
+    NegOneToOne, // OpenGL, near -1 far 1
+    ZeroToOne,   // Vulkan / D3D / Metal, near 0 far 1
+    ReverseZ,    // near 1 far 0, spreads float precision evenly over the depth
+}
+
+impl DepthRange {
+    fn bounds<T: RealScalar>(self) -> (T, T) {
+        match self {
+            DepthRange::NegOneToOne => (-T::one(), T::one()),
+            DepthRange::ZeroToOne => (T::zero(), T::one()),
+            DepthRange::ReverseZ => (T::one(), T::zero()),
+        }
+    }
+}
+
+// the unit axis f has the smallest component along
+fn least_aligned_axis<T: RealScalar>(f: &Vector3D<T>) -> Vector3D<T> {
+    let (zero, one) = (T::zero(), T::one());
+    let (x, y, z) = (f.x.norm(), f.y.norm(), f.z.norm());
+    if x <= y && x <= z {
+        Vector3D::new(one, zero, zero)
+    } else if y <= z {
+        Vector3D::new(zero, one, zero)
+    } else {
+        Vector3D::new(zero, zero, one)
+    }
+}
+
+impl Handedness {
+    // sign of z for points in front of the camera
+    fn forward<T: RealScalar>(self) -> T {
+        match self {
+            Handedness::Right => -T::one(),
+            Handedness::Left => T::one(),
+        }
+    }
+}
+
+impl<T: RealScalar> Matrix4x4<T> {
+    // view matrix, moves eye to the origin looking at target with up roughly up
+    pub fn look_at(
+        eye: &Vector3D<T>,
+        target: &Vector3D<T>,
+        up: &Vector3D<T>,
+        handedness: Handedness,
+    ) -> Self {
+        let zero = T::zero();
+        let one = T::one();
+        let f = target.sub(eye).normalized().unwrap_or(Vector3D::new(zero, zero, -one));
+        // with up along f any other up will do, the axis least aligned with f can't be parallel to it
+        let axis = least_aligned_axis(&f);
+        let (s, u, back) = match handedness {
+            Handedness::Right => {
+                let s = f.cross(up).normalized().or_else(|| f.cross(&axis).normalized()).unwrap_or(axis);
+                let u = s.cross(&f);
+                (s, u, f.invert())
+            }
+            Handedness::Left => {
+                let s = up.cross(&f).normalized().or_else(|| axis.cross(&f).normalized()).unwrap_or(axis);
+                let u = f.cross(&s);
+                (s, u, f)
+            }
+        };
+
+        Self::new([
+            [s.x, s.y, s.z, -s.dot(eye)],
+            [u.x, u.y, u.z, -u.dot(eye)],
+            [back.x, back.y, back.z, -back.dot(eye)],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    // fov_y is in radians, aspect is width / height
+    pub fn perspective(
+        fov_y: T,
+        aspect: T,
+        near: T,
+        far: T,
+        handedness: Handedness,
+        depth: DepthRange,
+    ) -> Self {
+        let (n0, f0) = depth.bounds::<T>();
+        // z_ndc = a + b / distance, solved so near -> n0 and far -> f0
+        let b = (n0 - f0) * near * far / (far - near);
+        let a = f0 - b / far;
+        Self::perspective_from(fov_y, aspect, a, b, handedness)
+    }
+
+    // far plane at infinity, only the near plane is clipped
+    pub fn perspective_infinite(
+        fov_y: T,
+        aspect: T,
+        near: T,
+        handedness: Handedness,
+        depth: DepthRange,
+    ) -> Self {
+        let (n0, f0) = depth.bounds::<T>();
+        Self::perspective_from(fov_y, aspect, f0, (n0 - f0) * near, handedness)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn orthographic(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        handedness: Handedness,
+        depth: DepthRange,
+    ) -> Self {
+        let zero = T::zero();
+        let one = T::one();
+        let two = one + one;
+        let (n0, f0) = depth.bounds::<T>();
+        let dz = (f0 - n0) / (far - near);
+
+        Self::new([
+            [two / (right - left), zero, zero, -(right + left) / (right - left)],
+            [zero, two / (top - bottom), zero, -(top + bottom) / (top - bottom)],
+            [zero, zero, dz * handedness.forward(), n0 - near * dz],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    fn perspective_from(fov_y: T, aspect: T, a: T, b: T, handedness: Handedness) -> Self {
+        let zero = T::zero();
+        let two = T::one() + T::one();
+        let focal = T::one() / (fov_y / two).tan();
+        let sz = handedness.forward();
+
+        Self::new([
+            [focal / aspect, zero, zero, zero],
+            [zero, focal, zero, zero],
+            [zero, zero, a * sz, b],
+            [zero, zero, sz, zero],
+        ])
+    }
+}
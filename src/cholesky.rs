@@ -0,0 +1,210 @@
+use crate::errors::MatrixErr;
+use crate::matrices::SquareMatrix;
+use crate::types::Scalar;
+use crate::util::{max_norm, rounding_tolerance};
+use crate::vectors::Vector;
+use num_traits::Float;
+use std::marker::PhantomData;
+
+// A = L * L^T for symmetric positive-definite A, only the lower triangle of A is read
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cholesky<T: Scalar, M> {
+    _n: usize,
+    _l: Vec<T>,
+    _matrix: PhantomData<M>,
+}
+
+// A = L * D * L^T with unit lower triangular L and diagonal D, no square roots needed
+#[derive(Debug, Clone, PartialEq)]
+pub struct LDLT<T: Scalar, M> {
+    _n: usize,
+    _l: Vec<T>,
+    _d: Vec<T>,
+    _matrix: PhantomData<M>,
+}
+
+impl<T: Scalar<Real = T> + Float, M: SquareMatrix<T>> Cholesky<T, M> {
+    pub fn new(m: &M) -> Result<Self, MatrixErr> {
+        let (n, _) = m.dimensions();
+        let tolerance = pivot_tolerance(m);
+        let mut l = vec![T::zero(); n * n];
+
+        for j in 0..n {
+            let mut d = m.get_val(j, j);
+            for k in 0..j {
+                d = d - l[j * n + k] * l[j * n + k];
+            }
+            if d.is_nan() || d <= tolerance {
+                return Err(MatrixErr::NotPositiveDefinite);
+            }
+            let d = d.sqrt();
+            l[j * n + j] = d;
+
+            for i in j + 1..n {
+                let mut s = m.get_val(i, j);
+                for k in 0..j {
+                    s = s - l[i * n + k] * l[j * n + k];
+                }
+                l[i * n + j] = s / d;
+            }
+        }
+
+        Ok(Self {
+            _n: n,
+            _l: l,
+            _matrix: PhantomData,
+        })
+    }
+
+    pub fn l(&self) -> M {
+        M::from_func(|i, j| self._l[i * self._n + j])
+    }
+
+    pub fn determinant(&self) -> T {
+        let d = (0..self._n).fold(T::one(), |acc, k| acc * self._l[k * self._n + k]);
+        d * d
+    }
+
+    pub fn solve(&self, b: &M::VecOut) -> Result<M::VecIn, MatrixErr> {
+        let x = self.solve_slice(&b.to_vec());
+        Ok(M::VecIn::from_slice(&x)?)
+    }
+
+    pub fn inverse(&self) -> M {
+        symmetric_inverse(self._n, |e| self.solve_slice(e))
+    }
+
+    fn solve_slice(&self, b: &[T]) -> Vec<T> {
+        let n = self._n;
+        let mut x = b.to_vec();
+        // L y = b
+        for i in 0..n {
+            for k in 0..i {
+                x[i] = x[i] - self._l[i * n + k] * x[k];
+            }
+            x[i] = x[i] / self._l[i * n + i];
+        }
+        // L^T x = y
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                x[i] = x[i] - self._l[k * n + i] * x[k];
+            }
+            x[i] = x[i] / self._l[i * n + i];
+        }
+        x
+    }
+}
+
+// both factorizations need the sign of the pivots, so they are only there for real types
+impl<T: Scalar<Real = T> + PartialOrd, M: SquareMatrix<T>> LDLT<T, M> {
+    // NotAField for integers, like LU dividing by the pivots would truncate
+    pub fn new(m: &M) -> Result<Self, MatrixErr> {
+        if !T::IS_FIELD {
+            return Err(MatrixErr::NotAField);
+        }
+        let (n, _) = m.dimensions();
+        let tolerance = pivot_tolerance(m);
+        let mut l: Vec<T> = (0..n * n)
+            .map(|k| {
+                if k / n == k % n {
+                    T::one()
+                } else {
+                    T::default()
+                }
+            })
+            .collect();
+        let mut d = vec![T::default(); n];
+
+        for j in 0..n {
+            let mut dj = m.get_val(j, j);
+            for k in 0..j {
+                dj = dj - l[j * n + k] * l[j * n + k] * d[k];
+            }
+            // written this way so NaN fails too
+            if dj.partial_cmp(&tolerance) != Some(std::cmp::Ordering::Greater) {
+                return Err(MatrixErr::NotPositiveDefinite);
+            }
+            d[j] = dj;
+
+            for i in j + 1..n {
+                let mut s = m.get_val(i, j);
+                for k in 0..j {
+                    s = s - l[i * n + k] * l[j * n + k] * d[k];
+                }
+                l[i * n + j] = s / dj;
+            }
+        }
+
+        Ok(Self {
+            _n: n,
+            _l: l,
+            _d: d,
+            _matrix: PhantomData,
+        })
+    }
+
+    pub fn l(&self) -> M {
+        M::from_func(|i, j| self._l[i * self._n + j])
+    }
+
+    // the diagonal of D
+    pub fn d(&self) -> Result<M::VecIn, MatrixErr> {
+        Ok(M::VecIn::from_slice(&self._d)?)
+    }
+
+    pub fn determinant(&self) -> T {
+        self._d.iter().fold(T::one(), |acc, d| acc * *d)
+    }
+
+    pub fn solve(&self, b: &M::VecOut) -> Result<M::VecIn, MatrixErr> {
+        let x = self.solve_slice(&b.to_vec());
+        Ok(M::VecIn::from_slice(&x)?)
+    }
+
+    pub fn inverse(&self) -> M {
+        symmetric_inverse(self._n, |e| self.solve_slice(e))
+    }
+
+    fn solve_slice(&self, b: &[T]) -> Vec<T> {
+        let n = self._n;
+        let mut x = b.to_vec();
+        // L z = b
+        for i in 0..n {
+            for k in 0..i {
+                x[i] = x[i] - self._l[i * n + k] * x[k];
+            }
+        }
+        // D y = z
+        for (xi, di) in x.iter_mut().zip(self._d.iter()) {
+            *xi = *xi / *di;
+        }
+        // L^T x = y
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                x[i] = x[i] - self._l[k * n + i] * x[k];
+            }
+        }
+        x
+    }
+}
+
+// pivots are measured against the largest diagonal entry
+fn pivot_tolerance<T: Scalar, M: SquareMatrix<T>>(m: &M) -> T::Real {
+    let (n, _) = m.dimensions();
+    let diagonal: Vec<T> = (0..n).map(|k| m.get_val(k, k)).collect();
+    rounding_tolerance::<T>(max_norm(&diagonal), n)
+}
+
+// solves against every column of the identity and mirrors the upper triangle, so the inverse is
+// exactly symmetric, unlike what the general inverse gives back
+fn symmetric_inverse<T: Scalar, M: SquareMatrix<T>>(n: usize, solve: impl Fn(&[T]) -> Vec<T>) -> M {
+    let columns: Vec<Vec<T>> = (0..n)
+        .map(|j| {
+            let e: Vec<T> = (0..n)
+                .map(|i| if i == j { T::one() } else { T::default() })
+                .collect();
+            solve(&e)
+        })
+        .collect();
+    M::from_func(|i, j| columns[i.min(j)][i.max(j)])
+}
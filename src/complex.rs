@@ -0,0 +1,220 @@
+use crate::types::Scalar;
+use num_traits::{Float, Num, One, ToPrimitive, Zero};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+// re + im * i, the parts are floats so the usual rounding tolerances apply
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<F> {
+    pub re: F,
+    pub im: F,
+}
+
+impl<F: Scalar<Real = F> + Float> Complex<F> {
+    pub fn new(re: F, im: F) -> Self {
+        Self { re, im }
+    }
+
+    pub fn i() -> Self {
+        Self::new(F::zero(), F::one())
+    }
+
+    // r * (cos(theta) + i * sin(theta))
+    pub fn from_polar(r: F, theta: F) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+
+    // |z|^2 without the square root
+    pub fn norm_sqr(&self) -> F {
+        self.re * self.re + self.im * self.im
+    }
+
+    // angle from the positive real axis, in (-pi, pi]
+    pub fn arg(&self) -> F {
+        self.im.atan2(self.re)
+    }
+
+    pub fn to_polar(&self) -> (F, F) {
+        (self.norm(), self.arg())
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> From<F> for Complex<F> {
+    fn from(re: F) -> Self {
+        Self::new(re, F::zero())
+    }
+}
+
+impl<F: Scalar<Real = F> + Float + Display> Display for Complex<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.im.is_sign_negative() {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Add for Complex<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Sub for Complex<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Mul for Complex<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Div for Complex<F> {
+    type Output = Self;
+
+    // Smith's algorithm, dividing through by the larger part keeps |rhs|^2 from overflowing
+    fn div(self, rhs: Self) -> Self {
+        if rhs.re.abs() >= rhs.im.abs() {
+            let r = rhs.im / rhs.re;
+            let d = rhs.re + rhs.im * r;
+            Self::new((self.re + self.im * r) / d, (self.im - self.re * r) / d)
+        } else {
+            let r = rhs.re / rhs.im;
+            let d = rhs.re * r + rhs.im;
+            Self::new((self.re * r + self.im) / d, (self.im * r - self.re) / d)
+        }
+    }
+}
+
+// what's left after taking out a whole multiple of rhs, with both parts of the quotient truncated
+impl<F: Scalar<Real = F> + Float> Rem for Complex<F> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        let q = self / rhs;
+        self - rhs * Self::new(q.re.trunc(), q.im.trunc())
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Neg for Complex<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Zero for Complex<F> {
+    fn zero() -> Self {
+        Self::new(F::zero(), F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> One for Complex<F> {
+    fn one() -> Self {
+        Self::new(F::one(), F::zero())
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Num for Complex<F> {
+    type FromStrRadixErr = F::FromStrRadixErr;
+
+    // "a", "bi", "a+bi" or "a-bi", a lone "i" counts as 1i
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let str = str.trim();
+        let parse = |s: &str| F::from_str_radix(s.trim(), radix);
+        let parse_im = |s: &str| match s.trim() {
+            "" | "+" => Ok(F::one()),
+            "-" => Ok(-F::one()),
+            s => parse(s),
+        };
+
+        let Some(body) = str.strip_suffix('i') else {
+            return Ok(Self::new(parse(str)?, F::zero()));
+        };
+        // the sign that splits the parts, skipping a leading sign and exponent signs like 1e-3
+        let split = body
+            .char_indices()
+            .rev()
+            .find(|&(k, c)| (c == '+' || c == '-') && k > 0 && !body[..k].ends_with(['e', 'E']));
+        match split {
+            Some((k, _)) => Ok(Self::new(parse(&body[..k])?, parse_im(&body[k..])?)),
+            None => Ok(Self::new(F::zero(), parse_im(body)?)),
+        }
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> ToPrimitive for Complex<F> {
+    // only numbers on the real axis convert, like for num-complex
+    fn to_i64(&self) -> Option<i64> {
+        if self.im.is_zero() {
+            self.re.to_i64()
+        } else {
+            None
+        }
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        if self.im.is_zero() {
+            self.re.to_u64()
+        } else {
+            None
+        }
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        if self.im.is_zero() {
+            self.re.to_f32()
+        } else {
+            None
+        }
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        if self.im.is_zero() {
+            self.re.to_f64()
+        } else {
+            None
+        }
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Scalar for Complex<F> {
+    type Real = F;
+
+    const IS_FIELD: bool = true;
+
+    // the modulus, hypot so the squares can't overflow on the way
+    fn norm(self) -> F {
+        self.re.hypot(self.im)
+    }
+
+    fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    fn machine_epsilon() -> F {
+        F::machine_epsilon()
+    }
+
+    fn is_finite(self) -> bool {
+        Float::is_finite(self.re) && Float::is_finite(self.im)
+    }
+}
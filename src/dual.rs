@@ -0,0 +1,558 @@
+use crate::matrices::{MatrixMN, MatrixOps};
+use crate::types::{RealScalar, Scalar, Sqrt};
+use crate::vectors::VectorN;
+use num_traits::{Float, Num, NumCast, One, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::num::FpCategory;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+// re + eps * ε with ε^2 = 0, running a function on re + 1ε gives f(re) + f'(re)ε,
+// so derivatives come out exact instead of through finite differences
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dual<F> {
+    pub re: F,
+    pub eps: F,
+}
+
+impl<F: Scalar<Real = F> + Float> Dual<F> {
+    pub fn new(re: F, eps: F) -> Self {
+        Self { re, eps }
+    }
+
+    // a value that doesn't depend on the input, its derivative is zero
+    pub fn constant(re: F) -> Self {
+        Self::new(re, F::zero())
+    }
+
+    // the input being differentiated against, d/dx x = 1
+    pub fn variable(re: F) -> Self {
+        Self::new(re, F::one())
+    }
+
+    // f(re) with the chain rule applied to eps, `df` is f' at re. a constant stays constant even
+    // where f' blows up (sqrt at zero), instead of turning into infinity * 0 = NaN
+    fn chain(self, f: F, df: F) -> Self {
+        if self.eps.is_zero() {
+            return Self::constant(f);
+        }
+        Self::new(f, df * self.eps)
+    }
+}
+
+// the gradient of f at x, one pass per input with that input as the variable
+pub fn gradient<F, const N: usize>(
+    f: impl Fn(VectorN<Dual<F>, N>) -> Dual<F>,
+    x: &VectorN<F, N>,
+) -> VectorN<F, N>
+where
+    F: Scalar<Real = F> + Float,
+{
+    VectorN::from_func(|j| f(seeded(x, j)).eps)
+}
+
+// the M x N jacobian of f at x, column j holds the derivatives with respect to x[j]
+pub fn jacobian<F, const N: usize, const M: usize>(
+    f: impl Fn(VectorN<Dual<F>, N>) -> VectorN<Dual<F>, M>,
+    x: &VectorN<F, N>,
+) -> MatrixMN<F, M, N>
+where
+    F: Scalar<Real = F> + Float,
+{
+    let columns: Vec<VectorN<Dual<F>, M>> = (0..N).map(|j| f(seeded(x, j))).collect();
+    MatrixMN::from_func(|i, j| columns[j].data[i].eps)
+}
+
+// x with x[j] as the variable and everything else constant
+fn seeded<F: Scalar<Real = F> + Float, const N: usize>(
+    x: &VectorN<F, N>,
+    j: usize,
+) -> VectorN<Dual<F>, N> {
+    VectorN::from_func(|k| {
+        if k == j {
+            Dual::variable(x.data[k])
+        } else {
+            Dual::constant(x.data[k])
+        }
+    })
+}
+
+impl<F: Scalar<Real = F> + Float> From<F> for Dual<F> {
+    fn from(re: F) -> Self {
+        Self::constant(re)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float + Display> Display for Dual<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.eps.is_sign_negative() {
+            write!(f, "{}-{}ε", self.re, -self.eps)
+        } else {
+            write!(f, "{}+{}ε", self.re, self.eps)
+        }
+    }
+}
+
+// ordered by value, the derivative only breaks ties so that == and the ordering agree
+impl<F: Scalar<Real = F> + Float> PartialOrd for Dual<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.re.partial_cmp(&other.re) {
+            Some(Ordering::Equal) => self.eps.partial_cmp(&other.eps),
+            ord => ord,
+        }
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Add for Dual<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Sub for Dual<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Mul for Dual<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.re * rhs.re, self.re * rhs.eps + self.eps * rhs.re)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Div for Dual<F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let re = self.re / rhs.re;
+        Self::new(re, (self.eps - re * rhs.eps) / rhs.re)
+    }
+}
+
+// a - b * trunc(a / b), the truncated quotient is piecewise constant
+impl<F: Scalar<Real = F> + Float> Rem for Dual<F> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        let q = (self.re / rhs.re).trunc();
+        Self::new(self.re % rhs.re, self.eps - rhs.eps * q)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Neg for Dual<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.eps)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Zero for Dual<F> {
+    fn zero() -> Self {
+        Self::constant(F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.eps.is_zero()
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> One for Dual<F> {
+    fn one() -> Self {
+        Self::constant(F::one())
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Num for Dual<F> {
+    type FromStrRadixErr = F::FromStrRadixErr;
+
+    // a plain number, which is a constant
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        F::from_str_radix(str, radix).map(Self::constant)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> ToPrimitive for Dual<F> {
+    // the value, the derivative is dropped
+    fn to_i64(&self) -> Option<i64> {
+        self.re.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.re.to_u64()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        self.re.to_f32()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.re.to_f64()
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> NumCast for Dual<F> {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        <F as NumCast>::from(n).map(Self::constant)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Scalar for Dual<F> {
+    type Real = Self;
+
+    const IS_FIELD: bool = true;
+
+    fn norm(self) -> Self {
+        Float::abs(self)
+    }
+
+    fn machine_epsilon() -> Self {
+        Self::constant(F::machine_epsilon())
+    }
+
+    fn is_finite(self) -> bool {
+        Float::is_finite(self.re) && Float::is_finite(self.eps)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> Sqrt for Dual<F> {
+    fn sqrt(self) -> Self {
+        Float::sqrt(self)
+    }
+}
+
+impl<F: Scalar<Real = F> + Float> RealScalar for Dual<F> {
+    fn sin(self) -> Self {
+        Float::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        Float::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        Float::tan(self)
+    }
+
+    fn asin(self) -> Self {
+        Float::asin(self)
+    }
+
+    fn acos(self) -> Self {
+        Float::acos(self)
+    }
+
+    fn atan(self) -> Self {
+        Float::atan(self)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        Float::atan2(self, other)
+    }
+
+    fn epsilon() -> Self {
+        Float::epsilon()
+    }
+}
+
+// the usual derivative rules, functions that are piecewise constant have a zero derivative
+impl<F: Scalar<Real = F> + Float> Float for Dual<F> {
+    fn nan() -> Self {
+        Self::constant(F::nan())
+    }
+
+    fn infinity() -> Self {
+        Self::constant(F::infinity())
+    }
+
+    fn neg_infinity() -> Self {
+        Self::constant(F::neg_infinity())
+    }
+
+    fn neg_zero() -> Self {
+        Self::constant(F::neg_zero())
+    }
+
+    fn min_value() -> Self {
+        Self::constant(F::min_value())
+    }
+
+    fn min_positive_value() -> Self {
+        Self::constant(F::min_positive_value())
+    }
+
+    fn epsilon() -> Self {
+        Self::constant(F::epsilon())
+    }
+
+    fn max_value() -> Self {
+        Self::constant(F::max_value())
+    }
+
+    // a broken derivative counts the same as a broken value, so these agree with Scalar::is_finite
+    fn is_nan(self) -> bool {
+        self.re.is_nan() || self.eps.is_nan()
+    }
+
+    fn is_infinite(self) -> bool {
+        !self.is_nan() && (self.re.is_infinite() || self.eps.is_infinite())
+    }
+
+    fn is_finite(self) -> bool {
+        Float::is_finite(self.re) && Float::is_finite(self.eps)
+    }
+
+    // the rest only look at the value
+    fn is_normal(self) -> bool {
+        self.re.is_normal()
+    }
+
+    fn classify(self) -> FpCategory {
+        self.re.classify()
+    }
+
+    fn floor(self) -> Self {
+        Self::constant(self.re.floor())
+    }
+
+    fn ceil(self) -> Self {
+        Self::constant(self.re.ceil())
+    }
+
+    fn round(self) -> Self {
+        Self::constant(self.re.round())
+    }
+
+    fn trunc(self) -> Self {
+        Self::constant(self.re.trunc())
+    }
+
+    fn fract(self) -> Self {
+        Self::new(self.re.fract(), self.eps)
+    }
+
+    fn abs(self) -> Self {
+        self.chain(self.re.abs(), self.re.signum())
+    }
+
+    fn signum(self) -> Self {
+        Self::constant(self.re.signum())
+    }
+
+    fn is_sign_positive(self) -> bool {
+        self.re.is_sign_positive()
+    }
+
+    fn is_sign_negative(self) -> bool {
+        self.re.is_sign_negative()
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    fn recip(self) -> Self {
+        let r = self.re.recip();
+        self.chain(r, -r * r)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::one();
+        }
+        let n_f = <F as NumCast>::from(n).unwrap();
+        self.chain(self.re.powi(n), n_f * self.re.powi(n - 1))
+    }
+
+    fn powf(self, n: Self) -> Self {
+        let re = self.re.powf(n.re);
+        let d_base = if self.eps.is_zero() {
+            F::zero()
+        } else {
+            n.re * self.re.powf(n.re - F::one()) * self.eps
+        };
+        // only look at ln(base) when the exponent actually varies, it's NaN for negative bases
+        let d_exp = if n.eps.is_zero() {
+            F::zero()
+        } else {
+            re * self.re.ln() * n.eps
+        };
+        Self::new(re, d_base + d_exp)
+    }
+
+    fn sqrt(self) -> Self {
+        let s = self.re.sqrt();
+        self.chain(s, (s + s).recip())
+    }
+
+    fn exp(self) -> Self {
+        let e = self.re.exp();
+        self.chain(e, e)
+    }
+
+    fn exp2(self) -> Self {
+        let e = self.re.exp2();
+        self.chain(e, e * F::from(2).unwrap().ln())
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.re.ln(), self.re.recip())
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Self {
+        self.chain(self.re.log2(), (self.re * F::from(2).unwrap().ln()).recip())
+    }
+
+    fn log10(self) -> Self {
+        self.chain(
+            self.re.log10(),
+            (self.re * F::from(10).unwrap().ln()).recip(),
+        )
+    }
+
+    fn to_degrees(self) -> Self {
+        let per_radian = F::one().to_degrees();
+        Self::new(self.re.to_degrees(), self.eps * per_radian)
+    }
+
+    fn to_radians(self) -> Self {
+        let per_degree = F::one().to_radians();
+        Self::new(self.re.to_radians(), self.eps * per_degree)
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other.re > self.re || self.re.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other.re < self.re || self.re.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        if self.re <= other.re {
+            Self::zero()
+        } else {
+            self - other
+        }
+    }
+
+    fn cbrt(self) -> Self {
+        let c = self.re.cbrt();
+        self.chain(c, (c * c * F::from(3).unwrap()).recip())
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        let h = self.re.hypot(other.re);
+        if self.eps.is_zero() && other.eps.is_zero() {
+            return Self::constant(h);
+        }
+        Self::new(h, (self.re * self.eps + other.re * other.eps) / h)
+    }
+
+    fn sin(self) -> Self {
+        self.chain(self.re.sin(), self.re.cos())
+    }
+
+    fn cos(self) -> Self {
+        self.chain(self.re.cos(), -self.re.sin())
+    }
+
+    fn tan(self) -> Self {
+        let t = self.re.tan();
+        self.chain(t, F::one() + t * t)
+    }
+
+    fn asin(self) -> Self {
+        self.chain(
+            self.re.asin(),
+            (F::one() - self.re * self.re).sqrt().recip(),
+        )
+    }
+
+    fn acos(self) -> Self {
+        self.chain(
+            self.re.acos(),
+            -(F::one() - self.re * self.re).sqrt().recip(),
+        )
+    }
+
+    fn atan(self) -> Self {
+        self.chain(self.re.atan(), (F::one() + self.re * self.re).recip())
+    }
+
+    // self is y and other is x, like for floats
+    fn atan2(self, other: Self) -> Self {
+        let (y, x) = (self.re, other.re);
+        // constants stay constant at the origin too, where the slope would be 0 / 0
+        if self.eps.is_zero() && other.eps.is_zero() {
+            return Self::constant(y.atan2(x));
+        }
+        Self::new(y.atan2(x), (x * self.eps - y * other.eps) / (x * x + y * y))
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (Float::sin(self), Float::cos(self))
+    }
+
+    fn exp_m1(self) -> Self {
+        self.chain(self.re.exp_m1(), self.re.exp())
+    }
+
+    fn ln_1p(self) -> Self {
+        self.chain(self.re.ln_1p(), (F::one() + self.re).recip())
+    }
+
+    fn sinh(self) -> Self {
+        self.chain(self.re.sinh(), self.re.cosh())
+    }
+
+    fn cosh(self) -> Self {
+        self.chain(self.re.cosh(), self.re.sinh())
+    }
+
+    fn tanh(self) -> Self {
+        let t = self.re.tanh();
+        self.chain(t, F::one() - t * t)
+    }
+
+    fn asinh(self) -> Self {
+        self.chain(
+            self.re.asinh(),
+            (self.re * self.re + F::one()).sqrt().recip(),
+        )
+    }
+
+    fn acosh(self) -> Self {
+        self.chain(
+            self.re.acosh(),
+            (self.re * self.re - F::one()).sqrt().recip(),
+        )
+    }
+
+    fn atanh(self) -> Self {
+        self.chain(self.re.atanh(), (F::one() - self.re * self.re).recip())
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.re.integer_decode()
+    }
+}
@@ -0,0 +1,493 @@
+use crate::errors::{MatrixErr, VectorErr};
+use crate::matrices::{
+    Matrix2x2, Matrix3x3, Matrix4x4, MatrixMN, MatrixOps, checked, checked_neg, exact_div,
+};
+use crate::lu::LU;
+use crate::qr::QR;
+use crate::types::{RealScalar, Scalar, SignedScalar, Sqrt};
+use crate::util::{determinant, f64_magnitude, normalize_slice};
+use crate::vectors::{Vector2D, Vector3D, Vector4D, VectorN};
+use num_traits::{Float, PrimInt};
+use std::ops::{Index, IndexMut};
+
+// heap allocated vector, the length is only known at runtime
+#[derive(Debug, Clone, PartialEq)]
+pub struct DVector<T: Scalar> {
+    _data: Vec<T>,
+}
+
+impl<T: Scalar> DVector<T> {
+    pub fn new(data: Vec<T>) -> Self {
+        Self { _data: data }
+    }
+
+    pub fn zero(len: usize) -> Self {
+        Self {
+            _data: vec![T::default(); len],
+        }
+    }
+
+    pub fn from_slice(data: &[T]) -> Self {
+        Self {
+            _data: data.to_vec(),
+        }
+    }
+
+    pub fn from_func(len: usize, f: impl Fn(usize) -> T) -> Self {
+        Self {
+            _data: (0..len).map(f).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self._data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self._data.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self._data
+    }
+
+    pub fn invert(&self) -> Self
+    where
+        T: SignedScalar,
+    {
+        Self::from_func(self.len(), |i| -self._data[i])
+    }
+
+    pub fn add(&self, other: &Self) -> Result<Self, VectorErr> {
+        self.check_len(other)?;
+        Ok(Self::from_func(self.len(), |i| self._data[i] + other._data[i]))
+    }
+
+    pub fn sub(&self, other: &Self) -> Result<Self, VectorErr> {
+        self.check_len(other)?;
+        Ok(Self::from_func(self.len(), |i| self._data[i] - other._data[i]))
+    }
+
+    pub fn dot(&self, other: &Self) -> Result<T, VectorErr> {
+        self.check_len(other)?;
+        Ok(self
+            ._data
+            .iter()
+            .zip(other._data.iter())
+            .fold(T::default(), |acc, (s, o)| acc + *s * *o))
+    }
+
+    pub fn scale(&self, a: T) -> Self {
+        Self::from_func(self.len(), |i| self._data[i] * a)
+    }
+
+    // integer vectors only, the float ones have magnitude()
+    pub fn magnitude_f32(&self) -> f32
+    where
+        T: PrimInt,
+    {
+        self.magnitude_f64() as f32
+    }
+
+    pub fn magnitude_f64(&self) -> f64
+    where
+        T: PrimInt,
+    {
+        f64_magnitude(&self._data)
+    }
+
+    pub fn magnitude(&self) -> T
+    where
+        T: Sqrt,
+    {
+        self._data
+            .iter()
+            .fold(T::default(), |acc, v| acc + *v * *v)
+            .sqrt()
+    }
+
+    // unit length vector, None for the zero vector
+    pub fn normalized(&self) -> Option<Self>
+    where
+        T: RealScalar,
+    {
+        normalize_slice(&self._data, T::zero()).map(Self::new)
+    }
+
+    pub fn precision_eq(&self, other: &Self, precision: T::Real) -> bool {
+        self.len() == other.len()
+            && self
+                ._data
+                .iter()
+                .zip(other._data.iter())
+                .all(|(s, o)| s.distance(*o) <= precision)
+    }
+
+    fn check_len(&self, other: &Self) -> Result<(), VectorErr> {
+        if self.len() != other.len() {
+            return Err(VectorErr::LengthErr {
+                expected: self.len(),
+                got: other.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<T: Scalar> Index<usize> for DVector<T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        &self._data[i]
+    }
+}
+
+impl<T: Scalar> IndexMut<usize> for DVector<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self._data[i]
+    }
+}
+
+impl<T: Scalar, const N: usize> From<VectorN<T, N>> for DVector<T> {
+    fn from(v: VectorN<T, N>) -> Self {
+        Self::new(v.data.to_vec())
+    }
+}
+
+impl<T: Scalar, const N: usize> TryFrom<DVector<T>> for VectorN<T, N> {
+    type Error = VectorErr;
+
+    fn try_from(v: DVector<T>) -> Result<Self, VectorErr> {
+        let data: [T; N] = v._data.try_into().map_err(|d: Vec<T>| VectorErr::LengthErr {
+            expected: N,
+            got: d.len(),
+        })?;
+        Ok(VectorN::new(data))
+    }
+}
+
+// heap allocated row-major matrix, the shape is only known at runtime
+#[derive(Debug, Clone, PartialEq)]
+pub struct DMatrix<T: Scalar> {
+    _rows: usize,
+    _cols: usize,
+    _data: Vec<T>,
+}
+
+impl<T: Scalar> DMatrix<T> {
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, VectorErr> {
+        if data.len() != rows * cols {
+            return Err(VectorErr::LengthErr {
+                expected: rows * cols,
+                got: data.len(),
+            });
+        }
+
+        Ok(Self {
+            _rows: rows,
+            _cols: cols,
+            _data: data,
+        })
+    }
+
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Self {
+            _rows: rows,
+            _cols: cols,
+            _data: vec![T::default(); rows * cols],
+        }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        Self::from_func(n, n, |i, j| if i == j { T::one() } else { T::default() })
+    }
+
+    pub fn from_func(rows: usize, cols: usize, f: impl Fn(usize, usize) -> T) -> Self {
+        let mut _data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                _data.push(f(i, j));
+            }
+        }
+        Self {
+            _rows: rows,
+            _cols: cols,
+            _data,
+        }
+    }
+
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self._rows, self._cols)
+    }
+
+    pub fn get_val(&self, i: usize, j: usize) -> T {
+        assert!(i < self._rows && j < self._cols, "Index ({}, {}) out of range", i, j);
+        self._data[i * self._cols + j]
+    }
+
+    pub fn try_get_val(&self, i: usize, j: usize) -> Result<T, MatrixErr> {
+        if i >= self._rows || j >= self._cols {
+            return Err(MatrixErr::IndexOutOfRange {
+                index: (i, j),
+                dimensions: self.dimensions(),
+            });
+        }
+        Ok(self._data[i * self._cols + j])
+    }
+
+    pub fn transposed(&self) -> Self {
+        Self::from_func(self._cols, self._rows, |i, j| self.get_val(j, i))
+    }
+
+    // conjugate transpose
+    pub fn adjoint(&self) -> Self {
+        Self::from_func(self._cols, self._rows, |i, j| self.get_val(j, i).conj())
+    }
+
+    pub fn add(&self, other: &Self) -> Result<Self, MatrixErr> {
+        self.check_same_shape(other)?;
+        Ok(Self::from_func(self._rows, self._cols, |i, j| {
+            self.get_val(i, j) + other.get_val(i, j)
+        }))
+    }
+
+    pub fn sub(&self, other: &Self) -> Result<Self, MatrixErr> {
+        self.check_same_shape(other)?;
+        Ok(Self::from_func(self._rows, self._cols, |i, j| {
+            self.get_val(i, j) - other.get_val(i, j)
+        }))
+    }
+
+    pub fn scale(&self, a: T) -> Self {
+        Self::from_func(self._rows, self._cols, |i, j| self.get_val(i, j) * a)
+    }
+
+    pub fn product(&self, other: &Self) -> Result<Self, MatrixErr> {
+        if self._cols != other._rows {
+            return Err(MatrixErr::DimensionErr {
+                expected: (self._cols, other._cols),
+                got: other.dimensions(),
+            });
+        }
+
+        Ok(Self::from_func(self._rows, other._cols, |i, j| {
+            let mut val = T::default();
+            for k in 0..self._cols {
+                val = val + self.get_val(i, k) * other.get_val(k, j)
+            }
+            val
+        }))
+    }
+
+    pub fn mul_vec(&self, v: &DVector<T>) -> Result<DVector<T>, MatrixErr> {
+        if self._cols != v.len() {
+            return Err(MatrixErr::DimensionErr {
+                expected: (self._cols, 1),
+                got: (v.len(), 1),
+            });
+        }
+
+        Ok(DVector::from_func(self._rows, |i| {
+            let mut val = T::default();
+            for j in 0..self._cols {
+                val = val + self.get_val(i, j) * v[j]
+            }
+            val
+        }))
+    }
+
+    pub fn determinant(&self) -> Result<T, MatrixErr> {
+        self.check_square()?;
+        let mut values = self._data.clone();
+        checked(determinant(&mut values, self._rows))
+    }
+
+    // LU for the field types, cofactors otherwise like the fixed-size matrices, so integer
+    // inverses are exact or fail with NotExact
+    pub fn inverse(&self) -> Result<Self, MatrixErr>
+    where
+        T: SignedScalar,
+    {
+        self.check_square()?;
+        if !T::IS_FIELD {
+            return self.cofactor_inverse();
+        }
+
+        let n = self._rows;
+        let lu = LU::<T, Self>::from_data(self._data.clone(), n)?;
+        if lu.is_singular() {
+            return Err(MatrixErr::Singular);
+        }
+        let columns = lu.inverse_columns();
+        Ok(Self::from_func(n, n, |i, j| columns[j][i]))
+    }
+
+    pub fn precision_eq(&self, other: &Self, precision: T::Real) -> bool {
+        self.dimensions() == other.dimensions()
+            && self
+                ._data
+                .iter()
+                .zip(other._data.iter())
+                .all(|(s, o)| s.distance(*o) <= precision)
+    }
+
+    fn cofactor_inverse(&self) -> Result<Self, MatrixErr> {
+        let n = self._rows;
+        let det = self.determinant()?;
+        if det == T::default() {
+            return Err(MatrixErr::Singular);
+        }
+
+        let mut inv = Vec::with_capacity(n * n);
+        for i in 0..n {
+            for j in 0..n {
+                let minor = self.minor(j, i)?;
+                let cofactor = if (i + j) % 2 == 0 {
+                    minor
+                } else {
+                    checked_neg(minor)?
+                };
+                inv.push(exact_div(cofactor, det)?);
+            }
+        }
+        Ok(Self {
+            _rows: n,
+            _cols: n,
+            _data: inv,
+        })
+    }
+
+    // determinant without row i and column j, the matrix is already known to be square
+    fn minor(&self, i: usize, j: usize) -> Result<T, MatrixErr> {
+        let n = self._rows;
+        let mut values: Vec<T> = (0..n * n)
+            .filter(|k| k / n != i && k % n != j)
+            .map(|k| self._data[k])
+            .collect();
+        checked(determinant(&mut values, n - 1))
+    }
+
+    fn check_same_shape(&self, other: &Self) -> Result<(), MatrixErr> {
+        if self.dimensions() != other.dimensions() {
+            return Err(MatrixErr::DimensionErr {
+                expected: self.dimensions(),
+                got: other.dimensions(),
+            });
+        }
+        Ok(())
+    }
+
+    fn check_square(&self) -> Result<(), MatrixErr> {
+        if self._rows != self._cols {
+            return Err(MatrixErr::DimensionErr {
+                expected: (self._rows, self._rows),
+                got: self.dimensions(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<T: Scalar + Float> DMatrix<T> {
+    // householder QR of any shape, Q is rows x rows and R rows x cols
+    pub fn qr(&self) -> (Self, Self) {
+        let (q, r) = QR::<T, Self>::from_rows(self._rows, self._cols, self._data.clone()).into_parts();
+        (
+            Self {
+                _rows: self._rows,
+                _cols: self._rows,
+                _data: q,
+            },
+            Self {
+                _rows: self._rows,
+                _cols: self._cols,
+                _data: r,
+            },
+        )
+    }
+
+    // x minimizing |self * x - b|, needs rows >= cols and full column rank
+    pub fn solve_least_squares(&self, b: &DVector<T>) -> Result<DVector<T>, MatrixErr> {
+        if b.len() != self._rows {
+            return Err(MatrixErr::DimensionErr {
+                expected: (self._rows, 1),
+                got: (b.len(), 1),
+            });
+        }
+        let qr = QR::<T, Self>::from_rows(self._rows, self._cols, self._data.clone());
+        Ok(DVector::new(qr.least_squares(b.as_slice())?))
+    }
+}
+
+impl<T: Scalar> Index<(usize, usize)> for DMatrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self._rows && j < self._cols, "Index ({}, {}) out of range", i, j);
+        &self._data[i * self._cols + j]
+    }
+}
+
+impl<T: Scalar> IndexMut<(usize, usize)> for DMatrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self._rows && j < self._cols, "Index ({}, {}) out of range", i, j);
+        &mut self._data[i * self._cols + j]
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> From<MatrixMN<T, R, C>> for DMatrix<T> {
+    fn from(m: MatrixMN<T, R, C>) -> Self {
+        Self::from_func(R, C, |i, j| m.get_val(i, j))
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> TryFrom<DMatrix<T>> for MatrixMN<T, R, C> {
+    type Error = MatrixErr;
+
+    fn try_from(m: DMatrix<T>) -> Result<Self, MatrixErr> {
+        if m.dimensions() != (R, C) {
+            return Err(MatrixErr::DimensionErr {
+                expected: (R, C),
+                got: m.dimensions(),
+            });
+        }
+        Ok(MatrixMN::from_func(|i, j| m.get_val(i, j)))
+    }
+}
+
+// conversions for the named fixed-size types
+macro_rules! impl_dynamic_conversions {
+    ($vec:ident, $mat:ident, $n:literal) => {
+        impl<T: Scalar> From<$vec<T>> for DVector<T> {
+            fn from(v: $vec<T>) -> Self {
+                VectorN::<T, $n>::from(v).into()
+            }
+        }
+
+        impl<T: Scalar> TryFrom<DVector<T>> for $vec<T> {
+            type Error = VectorErr;
+
+            fn try_from(v: DVector<T>) -> Result<Self, VectorErr> {
+                Ok(VectorN::<T, $n>::try_from(v)?.into())
+            }
+        }
+
+        impl<T: Scalar> From<$mat<T>> for DMatrix<T> {
+            fn from(m: $mat<T>) -> Self {
+                MatrixMN::<T, $n, $n>::from(m).into()
+            }
+        }
+
+        impl<T: Scalar> TryFrom<DMatrix<T>> for $mat<T> {
+            type Error = MatrixErr;
+
+            fn try_from(m: DMatrix<T>) -> Result<Self, MatrixErr> {
+                Ok(MatrixMN::<T, $n, $n>::try_from(m)?.into())
+            }
+        }
+    };
+}
+
+impl_dynamic_conversions!(Vector2D, Matrix2x2, 2);
+impl_dynamic_conversions!(Vector3D, Matrix3x3, 3);
+impl_dynamic_conversions!(Vector4D, Matrix4x4, 4);
@@ -0,0 +1,86 @@
+use crate::matrices::{Matrix3x3, MatrixOps};
+use crate::types::Scalar;
+use crate::util::MAX_SWEEPS;
+use crate::vectors::Vector3D;
+use num_traits::Float;
+
+impl<T: Scalar + Float> Matrix3x3<T> {
+    // eigenvalues in ascending order and the matching unit eigenvectors as columns,
+    // the matrix is assumed symmetric so only (A + A^T) / 2 is looked at
+    pub fn symmetric_eigen(&self) -> (Vector3D<T>, Matrix3x3<T>) {
+        let two = T::one() + T::one();
+        let a = (0..9)
+            .map(|k| (self.get_val(k / 3, k % 3) + self.get_val(k % 3, k / 3)) / two)
+            .collect();
+        let (values, vectors) = symmetric_eigen(a, 3);
+        (
+            Vector3D::new(values[0], values[1], values[2]),
+            Matrix3x3::from_func(|i, j| vectors[i * 3 + j]),
+        )
+    }
+}
+
+// jacobi eigenvalue algorithm on a row-major symmetric n x n matrix,
+// returns ascending eigenvalues and the eigenvectors as columns of a row-major matrix
+pub(crate) fn symmetric_eigen<T: Float>(mut a: Vec<T>, n: usize) -> (Vec<T>, Vec<T>) {
+    let mut v: Vec<T> = (0..n * n)
+        .map(|k| if k / n == k % n { T::one() } else { T::zero() })
+        .collect();
+    let norm_sq = a.iter().fold(T::zero(), |acc, x| acc + *x * *x);
+
+    for _ in 0..MAX_SWEEPS {
+        let mut off = T::zero();
+        for p in 0..n {
+            for q in p + 1..n {
+                off = off + a[p * n + q] * a[p * n + q];
+            }
+        }
+        if off <= T::epsilon() * T::epsilon() * norm_sq {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[p * n + q];
+                if apq == T::zero() {
+                    continue;
+                }
+
+                // rotation that zeroes a[p][q], t is the smaller root so the angle stays under 45 degrees
+                let theta = (a[q * n + q] - a[p * n + p]) / (apq + apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let c = T::one() / (t * t + T::one()).sqrt();
+                let s = t * c;
+
+                for k in 0..n {
+                    let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = c * akp - s * akq;
+                    a[k * n + q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = c * apk - s * aqk;
+                    a[q * n + k] = s * apk + c * aqk;
+                }
+                a[p * n + q] = T::zero();
+                a[q * n + p] = T::zero();
+
+                for k in 0..n {
+                    let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                    v[k * n + p] = c * vkp - s * vkq;
+                    v[k * n + q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+        a[i * n + i]
+            .partial_cmp(&a[j * n + j])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let values = order.iter().map(|&k| a[k * n + k]).collect();
+    let vectors = (0..n * n).map(|k| v[(k / n) * n + order[k % n]]).collect();
+    (values, vectors)
+}
@@ -25,3 +25,124 @@ impl Display for VectorErr {
 }
 
 impl std::error::Error for VectorErr {}
+
+pub enum MatrixErr {
+    DimensionErr {
+        expected: (usize, usize),
+        got: (usize, usize),
+    },
+    Singular,
+    IllConditioned,
+    NotPositiveDefinite,
+    IndexOutOfRange {
+        index: (usize, usize),
+        dimensions: (usize, usize),
+    },
+    NonFinite,
+    Overflow,
+    NotExact,
+    NotAField,
+}
+
+impl Debug for MatrixErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatrixErr::DimensionErr { expected, got } => {
+                write!(f, "Expected {}x{} got {}x{}", expected.0, expected.1, got.0, got.1)
+            }
+            MatrixErr::Singular => write!(f, "Matrix is singular"),
+            MatrixErr::IllConditioned => write!(f, "Matrix is too ill-conditioned to solve"),
+            MatrixErr::NotPositiveDefinite => write!(f, "Matrix is not positive-definite"),
+            MatrixErr::IndexOutOfRange { index, dimensions } => write!(
+                f,
+                "Index ({}, {}) out of range for {}x{}",
+                index.0, index.1, dimensions.0, dimensions.1
+            ),
+            MatrixErr::NonFinite => write!(f, "Matrix contains a NaN or infinite value"),
+            MatrixErr::Overflow => write!(f, "Arithmetic overflow"),
+            MatrixErr::NotExact => write!(f, "Result is not a whole number"),
+            MatrixErr::NotAField => write!(f, "Scalar type has no exact division"),
+        }
+    }
+}
+
+impl Display for MatrixErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatrixErr::DimensionErr { expected, got } => {
+                write!(f, "Expected {}x{} got {}x{}", expected.0, expected.1, got.0, got.1)
+            }
+            MatrixErr::Singular => write!(f, "Matrix is singular"),
+            MatrixErr::IllConditioned => write!(f, "Matrix is too ill-conditioned to solve"),
+            MatrixErr::NotPositiveDefinite => write!(f, "Matrix is not positive-definite"),
+            MatrixErr::IndexOutOfRange { index, dimensions } => write!(
+                f,
+                "Index ({}, {}) out of range for {}x{}",
+                index.0, index.1, dimensions.0, dimensions.1
+            ),
+            MatrixErr::NonFinite => write!(f, "Matrix contains a NaN or infinite value"),
+            MatrixErr::Overflow => write!(f, "Arithmetic overflow"),
+            MatrixErr::NotExact => write!(f, "Result is not a whole number"),
+            MatrixErr::NotAField => write!(f, "Scalar type has no exact division"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixErr {}
+
+// a vector is just an n x 1 matrix
+impl From<VectorErr> for MatrixErr {
+    fn from(err: VectorErr) -> Self {
+        match err {
+            VectorErr::LengthErr { expected, got } => MatrixErr::DimensionErr {
+                expected: (expected, 1),
+                got: (got, 1),
+            },
+        }
+    }
+}
+
+// everything the crate can fail with, for callers that don't care which part it came from
+pub enum MathErr {
+    Vector(VectorErr),
+    Matrix(MatrixErr),
+}
+
+impl Debug for MathErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MathErr::Vector(err) => write!(f, "Vector error: {:?}", err),
+            MathErr::Matrix(err) => write!(f, "Matrix error: {:?}", err),
+        }
+    }
+}
+
+impl Display for MathErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MathErr::Vector(err) => write!(f, "Vector error: {}", err),
+            MathErr::Matrix(err) => write!(f, "Matrix error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for MathErr {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MathErr::Vector(err) => Some(err),
+            MathErr::Matrix(err) => Some(err),
+        }
+    }
+}
+
+impl From<VectorErr> for MathErr {
+    fn from(err: VectorErr) -> Self {
+        MathErr::Vector(err)
+    }
+}
+
+impl From<MatrixErr> for MathErr {
+    fn from(err: MatrixErr) -> Self {
+        MathErr::Matrix(err)
+    }
+}
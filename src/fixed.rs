@@ -0,0 +1,337 @@
+use crate::types::{Scalar, Sqrt};
+use num_traits::{Num, One, ToPrimitive, Zero};
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+// binary fixed-point numbers, raw / 2^FRAC, everything is integer arithmetic so results are
+// bit-identical on every platform. the operators saturate at MIN / MAX instead of overflowing,
+// the checked_ versions from Scalar report it instead
+macro_rules! impl_fixed {
+    ($name:ident, $raw:ty, $wide:ty, $frac:expr) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name {
+            _raw: $raw,
+        }
+
+        impl $name {
+            pub const FRAC_BITS: u32 = $frac;
+            pub const ZERO: Self = Self::from_raw(0);
+            pub const ONE: Self = Self::from_raw(1 << $frac);
+            pub const MIN: Self = Self::from_raw(<$raw>::MIN);
+            pub const MAX: Self = Self::from_raw(<$raw>::MAX);
+            // the smallest step, 2^-FRAC
+            pub const EPSILON: Self = Self::from_raw(1);
+
+            pub const fn from_raw(raw: $raw) -> Self {
+                Self { _raw: raw }
+            }
+
+            pub const fn raw(&self) -> $raw {
+                self._raw
+            }
+
+            // saturates when n doesn't fit in the integer part
+            pub fn from_int(n: $raw) -> Self {
+                Self::from_wide((n as $wide) << $frac)
+            }
+
+            // rounds to the nearest step, only meant for setting things up, the simulation itself
+            // should never need a float
+            pub fn from_f64(v: f64) -> Self {
+                // `as` saturates and sends NaN to zero
+                Self::from_raw((v * (1u64 << $frac) as f64).round() as $raw)
+            }
+
+            // a / b truncated to a step, for constants that shouldn't come from a float
+            pub fn from_ratio(a: $raw, b: $raw) -> Self {
+                Self::from_int(a).saturating_div(Self::from_int(b))
+            }
+
+            pub fn abs(self) -> Self {
+                Self::from_raw(self._raw.saturating_abs())
+            }
+
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                Self::from_raw(self._raw.saturating_add(rhs._raw))
+            }
+
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                Self::from_raw(self._raw.saturating_sub(rhs._raw))
+            }
+
+            // rounds to the nearest step, ties go up
+            pub fn saturating_mul(self, rhs: Self) -> Self {
+                Self::from_wide(Self::wide_mul(self, rhs))
+            }
+
+            // truncates towards zero like integer division, dividing by zero gives MAX or MIN
+            // depending on the sign of self and 0 / 0 is 0
+            pub fn saturating_div(self, rhs: Self) -> Self {
+                if rhs._raw == 0 {
+                    return match self._raw.cmp(&0) {
+                        std::cmp::Ordering::Greater => Self::MAX,
+                        std::cmp::Ordering::Less => Self::MIN,
+                        std::cmp::Ordering::Equal => Self::ZERO,
+                    };
+                }
+                Self::from_wide(((self._raw as $wide) << $frac) / rhs._raw as $wide)
+            }
+
+            // largest step whose square is at most self, negative numbers give zero
+            pub fn sqrt(self) -> Self {
+                if self._raw <= 0 {
+                    return Self::ZERO;
+                }
+                // sqrt(raw / 2^F) * 2^F = sqrt(raw * 2^F), which always fits back into raw
+                let root = (((self._raw as $wide) << $frac) as u128).isqrt();
+                Self::from_raw(root as $raw)
+            }
+
+            fn wide_mul(a: Self, b: Self) -> $wide {
+                let product = a._raw as $wide * b._raw as $wide;
+                (product + (1 << ($frac - 1))) >> $frac
+            }
+
+            fn from_wide(v: $wide) -> Self {
+                Self::from_raw(v.clamp(<$raw>::MIN as $wide, <$raw>::MAX as $wide) as $raw)
+            }
+        }
+
+        impl From<$raw> for $name {
+            fn from(n: $raw) -> Self {
+                Self::from_int(n)
+            }
+        }
+
+        // exact, 2^-FRAC always has a terminating decimal expansion
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                let abs = (self._raw as $wide).unsigned_abs();
+                let mask = (1 << $frac) - 1;
+                if self._raw < 0 {
+                    write!(f, "-")?;
+                }
+                write!(f, "{}", abs >> $frac)?;
+                let mut frac = abs & mask;
+                if frac != 0 {
+                    write!(f, ".")?;
+                }
+                while frac != 0 {
+                    frac *= 10;
+                    write!(f, "{}", frac >> $frac)?;
+                    frac &= mask;
+                }
+                Ok(())
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                self.saturating_add(rhs)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                self.saturating_sub(rhs)
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                self.saturating_mul(rhs)
+            }
+        }
+
+        impl Div for $name {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self {
+                self.saturating_div(rhs)
+            }
+        }
+
+        // exact, both sides are on the same grid. panics on zero like integer remainder
+        impl Rem for $name {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self {
+                Self::from_raw(self._raw.wrapping_rem(rhs._raw))
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self::from_raw(self._raw.saturating_neg())
+            }
+        }
+
+        impl Zero for $name {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn is_zero(&self) -> bool {
+                self._raw == 0
+            }
+        }
+
+        impl One for $name {
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl Num for $name {
+            type FromStrRadixErr = ParseFixedErr;
+
+            // "-12.375", the fraction is rounded to the nearest step
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, ParseFixedErr> {
+                let raw = parse_fixed(str, radix, $frac)?;
+                <$raw>::try_from(raw)
+                    .map(Self::from_raw)
+                    .map_err(|_| ParseFixedErr::OutOfRange)
+            }
+        }
+
+        impl ToPrimitive for $name {
+            // the integer conversions truncate towards zero
+            fn to_i64(&self) -> Option<i64> {
+                (self._raw / (1 << $frac)).to_i64()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                (self._raw / (1 << $frac)).to_u64()
+            }
+
+            fn to_f32(&self) -> Option<f32> {
+                Some(self._raw as f32 / (1u64 << $frac) as f32)
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                Some(self._raw as f64 / (1u64 << $frac) as f64)
+            }
+        }
+
+        impl Scalar for $name {
+            type Real = Self;
+
+            fn norm(self) -> Self {
+                self.abs()
+            }
+
+            // absolute rather than relative, the rounding error of one operation is a single step
+            fn machine_epsilon() -> Self {
+                Self::EPSILON
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                self._raw.checked_add(rhs._raw).map(Self::from_raw)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self._raw.checked_sub(rhs._raw).map(Self::from_raw)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$raw>::try_from(Self::wide_mul(self, rhs))
+                    .ok()
+                    .map(Self::from_raw)
+            }
+
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                if rhs._raw == 0 {
+                    return None;
+                }
+                <$raw>::try_from(((self._raw as $wide) << $frac) / rhs._raw as $wide)
+                    .ok()
+                    .map(Self::from_raw)
+            }
+        }
+
+        impl Sqrt for $name {
+            fn sqrt(self) -> Self {
+                $name::sqrt(self)
+            }
+        }
+    };
+}
+
+impl_fixed!(Q16_16, i32, i64, 16);
+impl_fixed!(Q32_32, i64, i128, 32);
+
+pub enum ParseFixedErr {
+    Invalid,
+    OutOfRange,
+}
+
+impl Debug for ParseFixedErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseFixedErr::Invalid => write!(f, "Invalid fixed-point number"),
+            ParseFixedErr::OutOfRange => write!(f, "Fixed-point number out of range"),
+        }
+    }
+}
+
+impl Display for ParseFixedErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseFixedErr::Invalid => write!(f, "Invalid fixed-point number"),
+            ParseFixedErr::OutOfRange => write!(f, "Fixed-point number out of range"),
+        }
+    }
+}
+
+impl std::error::Error for ParseFixedErr {}
+
+// the raw value of "[-]int[.frac]" with `frac_bits` fractional bits, before range checking
+fn parse_fixed(str: &str, radix: u32, frac_bits: u32) -> Result<i128, ParseFixedErr> {
+    let str = str.trim();
+    let (negative, str) = match str.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, str.strip_prefix('+').unwrap_or(str)),
+    };
+    let (int, frac) = str.split_once('.').unwrap_or((str, ""));
+    if int.is_empty() && frac.is_empty() {
+        return Err(ParseFixedErr::Invalid);
+    }
+
+    let digit = |c: char| {
+        c.to_digit(radix)
+            .map(i128::from)
+            .ok_or(ParseFixedErr::Invalid)
+    };
+    let mut raw: i128 = 0;
+    for c in int.chars() {
+        let d = digit(c)?;
+        raw = raw
+            .checked_mul(radix.into())
+            .and_then(|r| r.checked_add(d))
+            .ok_or(ParseFixedErr::OutOfRange)?;
+    }
+    raw = raw
+        .checked_shl(frac_bits)
+        .filter(|r| r >> frac_bits == raw)
+        .ok_or(ParseFixedErr::OutOfRange)?;
+
+    // digits past what the denominator can hold are too small to change the result
+    let (mut num, mut den): (i128, i128) = (0, 1);
+    for c in frac.chars() {
+        let d = digit(c)?;
+        if den < 1 << 80 {
+            num = num * i128::from(radix) + d;
+            den *= i128::from(radix);
+        }
+    }
+    raw += ((num << frac_bits) + den / 2) / den;
+    Ok(if negative { -raw } else { raw })
+}
@@ -0,0 +1,417 @@
+use crate::types::{Scalar, Sqrt};
+use num_traits::{Float, Num, One, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+// every real number the computation could have meant, [lo, hi] always contains the exact result.
+// each bound is rounded outwards by one step whenever the float operation wasn't exact, which is
+// found out with error-free transformations so exact integer work keeps point intervals.
+// the bounds are f32 or f64, see IntervalBound
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Interval<F> {
+    _lo: F,
+    _hi: F,
+}
+
+// what the bounds can be. sealed to f32 and f64, as a float type with an unfused mul_add (like
+// Dual's) would lose the rounding errors and hand back intervals that don't enclose the result
+pub trait IntervalBound: sealed::Sealed + Scalar<Real = Self> + Float {}
+
+impl IntervalBound for f32 {}
+impl IntervalBound for f64 {}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+impl<F: IntervalBound> Interval<F> {
+    // panics when lo is above hi
+    pub fn new(lo: F, hi: F) -> Self {
+        Self::try_new(lo, hi).expect("Lower bound is above the upper bound")
+    }
+
+    pub fn try_new(lo: F, hi: F) -> Option<Self> {
+        if lo <= hi {
+            Some(Self { _lo: lo, _hi: hi })
+        } else {
+            None
+        }
+    }
+
+    // exactly x, no rounding has happened yet
+    pub fn point(x: F) -> Self {
+        Self { _lo: x, _hi: x }
+    }
+
+    // every number, what dividing by something that might be zero gives
+    pub fn entire() -> Self {
+        Self {
+            _lo: F::neg_infinity(),
+            _hi: F::infinity(),
+        }
+    }
+
+    pub fn lo(&self) -> F {
+        self._lo
+    }
+
+    pub fn hi(&self) -> F {
+        self._hi
+    }
+
+    pub fn midpoint(&self) -> F {
+        let two = F::one() + F::one();
+        self._lo / two + self._hi / two
+    }
+
+    // rounded up, so the interval always fits in midpoint +- width / 2
+    pub fn width(&self) -> F {
+        round_up(self._hi - self._lo, two_diff(self._hi, self._lo))
+    }
+
+    pub fn contains(&self, x: F) -> bool {
+        self._lo <= x && x <= self._hi
+    }
+
+    pub fn contains_zero(&self) -> bool {
+        self.contains(F::zero())
+    }
+
+    // the sign of everything in the interval, None when it contains numbers of both signs
+    pub fn sign(&self) -> Option<Ordering> {
+        if self._lo > F::zero() {
+            Some(Ordering::Greater)
+        } else if self._hi < F::zero() {
+            Some(Ordering::Less)
+        } else if self._lo == F::zero() && self._hi == F::zero() {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+
+    // smallest interval containing both
+    pub fn hull(&self, other: &Self) -> Self {
+        Self {
+            _lo: self._lo.min(other._lo),
+            _hi: self._hi.max(other._hi),
+        }
+    }
+
+    // negative parts are cut off, an interval below zero gives NaN bounds like a float would
+    pub fn sqrt(self) -> Self {
+        let bound = |x: F, up: bool| {
+            if x <= F::zero() {
+                return x.sqrt();
+            }
+            let s = x.sqrt();
+            let err = if x < tiny() {
+                F::nan()
+            } else {
+                (-s).mul_add(s, x)
+            };
+            if up {
+                round_up(s, err)
+            } else {
+                round_down(s, err)
+            }
+        };
+        Self {
+            _lo: bound(self._lo.max(F::zero()), false),
+            _hi: bound(self._hi, true),
+        }
+    }
+
+    // |x| for every x in the interval
+    pub fn abs(self) -> Self {
+        if self._lo >= F::zero() {
+            self
+        } else if self._hi <= F::zero() {
+            -self
+        } else {
+            Self {
+                _lo: F::zero(),
+                _hi: (-self._lo).max(self._hi),
+            }
+        }
+    }
+
+    fn from_bounds(lo: F, hi: F) -> Self {
+        Self { _lo: lo, _hi: hi }
+    }
+}
+
+// below this a product or quotient may have lost bits to underflow, so its error term can't be trusted
+fn tiny<F: Float>() -> F {
+    F::min_positive_value() / F::epsilon()
+}
+
+// x is a rounded result and err how far the exact result lies above it, NaN when that's unknown
+fn round_down<F: Float>(x: F, err: F) -> F {
+    if x.is_nan() || x == F::neg_infinity() {
+        return x;
+    }
+    if x == F::infinity() {
+        return F::max_value();
+    }
+    if err >= F::zero() {
+        return x;
+    }
+    // at least one step, min_positive_value covers zero and the subnormals
+    x - (x.abs() * F::epsilon() + F::min_positive_value())
+}
+
+fn round_up<F: Float>(x: F, err: F) -> F {
+    -round_down(-x, -err)
+}
+
+// a + b - fl(a + b), exact (Knuth's TwoSum)
+fn two_sum<F: Float>(a: F, b: F) -> F {
+    let s = a + b;
+    let bb = s - a;
+    (a - (s - bb)) + (b - bb)
+}
+
+fn two_diff<F: Float>(a: F, b: F) -> F {
+    two_sum(a, -b)
+}
+
+// (lower, upper) bounds of a * b
+fn product<F: Float>(a: F, b: F) -> (F, F) {
+    // zero times anything is zero here, even for the infinite bounds of an unbounded interval
+    if a.is_zero() || b.is_zero() {
+        return (F::zero(), F::zero());
+    }
+    let p = a * b;
+    let err = if p.abs() < tiny() {
+        F::nan()
+    } else {
+        a.mul_add(b, -p)
+    };
+    (round_down(p, err), round_up(p, err))
+}
+
+// (lower, upper) bounds of a / b, b is not zero
+fn quotient<F: Float>(a: F, b: F) -> (F, F) {
+    if a.is_zero() {
+        return (F::zero(), F::zero());
+    }
+    let q = a / b;
+    // a - q * b is exact, the exact quotient is q + (a - q * b) / b
+    let err = if q.abs() < tiny() || a.abs() < tiny() {
+        F::nan()
+    } else {
+        (-q).mul_add(b, a) / b
+    };
+    (round_down(q, err), round_up(q, err))
+}
+
+impl<F: IntervalBound> From<F> for Interval<F> {
+    fn from(x: F) -> Self {
+        Self::point(x)
+    }
+}
+
+impl<F: IntervalBound + Display> Display for Interval<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self._lo, self._hi)
+    }
+}
+
+// certainly less or certainly greater, overlapping intervals can't be ordered.
+// Equal only for identical intervals so that it agrees with ==
+impl<F: IntervalBound> PartialOrd for Interval<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self._hi < other._lo {
+            Some(Ordering::Less)
+        } else if self._lo > other._hi {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl<F: IntervalBound> Add for Interval<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (lo, hi) = (self._lo + rhs._lo, self._hi + rhs._hi);
+        Self::from_bounds(
+            round_down(lo, two_sum(self._lo, rhs._lo)),
+            round_up(hi, two_sum(self._hi, rhs._hi)),
+        )
+    }
+}
+
+impl<F: IntervalBound> Sub for Interval<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<F: IntervalBound> Mul for Interval<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let bounds = [
+            product(self._lo, rhs._lo),
+            product(self._lo, rhs._hi),
+            product(self._hi, rhs._lo),
+            product(self._hi, rhs._hi),
+        ];
+        let lo = bounds.iter().fold(F::infinity(), |acc, b| acc.min(b.0));
+        let hi = bounds.iter().fold(F::neg_infinity(), |acc, b| acc.max(b.1));
+        Self::from_bounds(lo, hi)
+    }
+}
+
+impl<F: IntervalBound> Div for Interval<F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        if rhs.contains_zero() {
+            return Self::entire();
+        }
+        let bounds = [
+            quotient(self._lo, rhs._lo),
+            quotient(self._lo, rhs._hi),
+            quotient(self._hi, rhs._lo),
+            quotient(self._hi, rhs._hi),
+        ];
+        let lo = bounds.iter().fold(F::infinity(), |acc, b| acc.min(b.0));
+        let hi = bounds.iter().fold(F::neg_infinity(), |acc, b| acc.max(b.1));
+        Self::from_bounds(lo, hi)
+    }
+}
+
+// a - b * trunc(a / b), truncation is monotonic so truncating the bounds of the quotient is enough
+impl<F: IntervalBound> Rem for Interval<F> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        let q = self / rhs;
+        self - rhs * Self::from_bounds(q._lo.trunc(), q._hi.trunc())
+    }
+}
+
+impl<F: IntervalBound> Neg for Interval<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_bounds(-self._hi, -self._lo)
+    }
+}
+
+impl<F: IntervalBound> Zero for Interval<F> {
+    fn zero() -> Self {
+        Self::point(F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self._lo.is_zero() && self._hi.is_zero()
+    }
+}
+
+impl<F: IntervalBound> One for Interval<F> {
+    fn one() -> Self {
+        Self::point(F::one())
+    }
+}
+
+impl<F: IntervalBound> Num for Interval<F> {
+    type FromStrRadixErr = ParseIntervalErr<F::FromStrRadixErr>;
+
+    // "[lo, hi]" or a single number. the text may not be representable (0.1 isn't),
+    // so the parsed bounds are widened by a step to be sure they still enclose it
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let str = str.trim();
+        let (lo, hi) = match str.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            Some(inner) => inner.split_once(',').ok_or(ParseIntervalErr::Format)?,
+            None => (str, str),
+        };
+        let lo = F::from_str_radix(lo.trim(), radix).map_err(ParseIntervalErr::Float)?;
+        let hi = F::from_str_radix(hi.trim(), radix).map_err(ParseIntervalErr::Float)?;
+        if lo > hi {
+            return Err(ParseIntervalErr::Format);
+        }
+        Ok(Self::from_bounds(
+            round_down(lo, F::nan()),
+            round_up(hi, F::nan()),
+        ))
+    }
+}
+
+impl<F: IntervalBound> ToPrimitive for Interval<F> {
+    // the midpoint
+    fn to_i64(&self) -> Option<i64> {
+        self.midpoint().to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.midpoint().to_u64()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        self.midpoint().to_f32()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.midpoint().to_f64()
+    }
+}
+
+impl<F: IntervalBound> Scalar for Interval<F> {
+    // intervals are the real type themselves, tolerances stay zero because the bounds already
+    // account for every rounding. IS_FIELD stays false as a / b * b only contains a, so the
+    // matrices invert with cofactors, which keeps the enclosures tighter than elimination
+    type Real = Self;
+
+    fn norm(self) -> Self {
+        Interval::abs(self)
+    }
+
+    fn is_finite(self) -> bool {
+        Float::is_finite(self._lo) && Float::is_finite(self._hi)
+    }
+}
+
+impl<F: IntervalBound> Sqrt for Interval<F> {
+    fn sqrt(self) -> Self {
+        Interval::sqrt(self)
+    }
+}
+
+pub enum ParseIntervalErr<E> {
+    Float(E),
+    Format,
+}
+
+impl<E: Debug> Debug for ParseIntervalErr<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseIntervalErr::Float(err) => write!(f, "{:?}", err),
+            ParseIntervalErr::Format => write!(f, "Expected x or [lo, hi] with lo <= hi"),
+        }
+    }
+}
+
+impl<E: Display> Display for ParseIntervalErr<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseIntervalErr::Float(err) => write!(f, "{}", err),
+            ParseIntervalErr::Format => write!(f, "Expected x or [lo, hi] with lo <= hi"),
+        }
+    }
+}
+
+// num_traits' ParseFloatError is only Debug + Display, so the inner error isn't exposed as a source
+impl<E: Debug + Display> std::error::Error for ParseIntervalErr<E> {}
@@ -1,13 +1,41 @@
-mod vectors;
-mod errors;
-mod matrices;
-mod types;
+pub mod vectors;
+pub mod errors;
+pub mod matrices;
+pub mod dynamic;
+pub mod lu;
+pub mod qr;
+pub mod eigen;
+pub mod svd;
+pub mod cholesky;
+pub mod rational;
+pub mod complex;
+pub mod fixed;
+pub mod dual;
+pub mod interval;
+pub mod quaternion;
+pub mod transforms;
+pub mod camera;
+pub mod types;
+pub mod prelude;
 mod util;
 
 #[cfg(test)]
 mod tests {
     use crate::vectors::*;
     use crate::matrices::*;
+    use crate::dynamic::*;
+    use crate::lu::*;
+    use crate::qr::*;
+    use crate::errors::*;
+    use crate::rational::*;
+    use crate::complex::*;
+    use crate::fixed::*;
+    use crate::dual::*;
+    use crate::interval::*;
+    use crate::types::{Scalar, SignedScalar};
+    use num_traits::{Float, Num, ToPrimitive};
+    use crate::quaternion::*;
+    use crate::camera::*;
 
     fn f32_eq(f1: f32, f2: f32) -> bool {
         (f1 - f2).abs() <= f32::EPSILON
@@ -114,7 +142,7 @@ mod tests {
     }
 
     #[test]
-    fn vec_normalize() {
+    fn vec_scale_to_max_abs() {
         let v2d = Vector2D::new(5, -2);
         let v2drf32 = Vector2D::new(1.0f32, -0.4);
         let v2drf64 = Vector2D::new(1.0f64, -0.4);
@@ -127,12 +155,71 @@ mod tests {
         let v4drf32 = Vector4D::new(1.0f32, -0.4, 0.8, -0.2);
         let v4drf64 = Vector4D::new(1.0f64, -0.4, 0.8, -0.2);
 
-        assert_eq!(v2d.normalize_f32(), v2drf32);
-        assert_eq!(v2d.normalize_f64(), v2drf64);
-        assert_eq!(v3d.normalize_f32(), v3drf32);
-        assert_eq!(v3d.normalize_f64(), v3drf64);
-        assert_eq!(v4d.normalize_f32(), v4drf32);
-        assert_eq!(v4d.normalize_f64(), v4drf64);
+        assert_eq!(v2d.scale_to_max_abs_f32(), v2drf32);
+        assert_eq!(v2d.scale_to_max_abs_f64(), v2drf64);
+        assert_eq!(v3d.scale_to_max_abs_f32(), v3drf32);
+        assert_eq!(v3d.scale_to_max_abs_f64(), v3drf64);
+        assert_eq!(v4d.scale_to_max_abs_f32(), v4drf32);
+        assert_eq!(v4d.scale_to_max_abs_f64(), v4drf64);
+    }
+
+    #[test]
+    fn vec_scale_to_max_abs_zero() {
+        assert_eq!(Vector3D::new(0, 0, 0).scale_to_max_abs_f32(), Vector3D::new(0f32, 0., 0.));
+        assert_eq!(Vector2D::new(3, 3).scale_to_max_abs_f64(), Vector2D::new(1f64, 1.));
+    }
+
+    #[test]
+    fn vec_normalized() {
+        let v2 = Vector2D::new(3., 3.).normalized().unwrap();
+        assert!(v2.precision_eq(&Vector2D::new(0.5f64.sqrt(), 0.5f64.sqrt()), 1e-12));
+
+        let v3 = Vector3D::new(2., -3., 6.).normalized().unwrap();
+        assert!(v3.precision_eq(&Vector3D::new(2. / 7., -3. / 7., 6. / 7.), 1e-12));
+        assert!(f64_eq(v3.magnitude(), 1.));
+
+        let v4 = Vector4D::new(0f32, 0., 0., -5.).normalized().unwrap();
+        assert_eq!(v4, Vector4D::new(0., 0., 0., -1.));
+
+        assert!(Vector3D::<f64>::zero().normalized().is_none());
+        assert!(VectorN::new([0f32; 5]).normalized().is_none());
+    }
+
+    #[test]
+    fn vec_try_normalize() {
+        let tiny = Vector3D::new(1e-9, 0., 0.);
+        assert!(tiny.try_normalize(1e-6).is_none());
+        assert!(tiny.try_normalize(1e-12).unwrap().precision_eq(&Vector3D::new(1., 0., 0.), 1e-12));
+        assert!(Vector2D::new(f64::NAN, 1.).try_normalize(0.).is_none());
+
+        // the squares would overflow to infinity (or underflow to zero) without scaling first
+        let half = 0.5f64.sqrt();
+        let big = Vector2D::new(1e200, 1e200).normalized().unwrap();
+        assert!(big.precision_eq(&Vector2D::new(half, half), 1e-12));
+        let small = Vector2D::new(1e-200, -1e-200).normalized().unwrap();
+        assert!(small.precision_eq(&Vector2D::new(half, -half), 1e-12));
+        let dbig = DVector::new(vec![1e200, 1e200]).normalized().unwrap();
+        assert!(dbig.precision_eq(&DVector::new(vec![half, half]), 1e-12));
+        assert!(Vector2D::new(f64::INFINITY, 1.).normalized().is_none());
+    }
+
+    #[test]
+    fn vec_normalized_int() {
+        let v2 = Vector2D::new(3, 4);
+        assert!(v2.normalized_f32().unwrap().precision_eq(&Vector2D::new(0.6, 0.8), 1e-6));
+        assert!(v2.normalized_f64().unwrap().precision_eq(&Vector2D::new(0.6, 0.8), 1e-12));
+
+        // the sum of squares would overflow i64 if it were computed in integers
+        let big = Vector3D::new(i64::MAX, 0, i64::MAX);
+        let half = 0.5f64.sqrt();
+        assert!(big.normalized_f64().unwrap().precision_eq(&Vector3D::new(half, 0., half), 1e-12));
+        assert!(Vector4D::new(0, 0, 0, 0).normalized_f64().is_none());
+
+        // u128::MAX is past f32::MAX, it only narrows after being scaled down in f64
+        let huge = Vector2D::new(u128::MAX, 1).normalized_f32().unwrap();
+        assert!(huge.precision_eq(&Vector2D::new(1., 0.), 1e-6));
+        assert_eq!(Vector2D::new(u128::MAX, 0).magnitude_f64(), u128::MAX as f64);
+        assert_eq!(DVector::new(vec![u64::MAX, 0]).magnitude_f64(), u64::MAX as f64);
     }
 
     #[test]
@@ -191,6 +278,101 @@ mod tests {
         assert_eq!(v4d1.sub(&v4d2), v4dr);
     }
     
+    #[test]
+    fn vec_add_op() {
+        let v2d1 = Vector2D::new(2.0, 4.0);
+        let v2d2 = Vector2D::new(-7.0, 2.0);
+        let v2dr = Vector2D::new(-5.0, 6.0);
+
+        let v3d1 = Vector3D::new(2.0, 4.0, 5.2);
+        let v3d2 = Vector3D::new(-7.0, 2.0, 0.6);
+        let v3dr = Vector3D::new(-5.0, 6.0, 5.8);
+
+        let v4d1 = Vector4D::new(2.0, 4.0, 5.2, 0.0);
+        let v4d2 = Vector4D::new(-7.0, 2.0, 0.6, -15.2);
+        let v4dr = Vector4D::new(-5.0, 6.0, 5.8, -15.2);
+
+        assert_eq!(&v2d1 + &v2d2, v2dr);
+        assert_eq!(&v3d1 + &v3d2, v3dr);
+        assert_eq!(&v4d1 + &v4d2, v4dr);
+        assert_eq!(v2d1.clone() + v2d2.clone(), v2dr);
+        assert_eq!(v3d1.clone() + v3d2.clone(), v3dr);
+        assert_eq!(v4d1.clone() + v4d2.clone(), v4dr);
+
+        let mut v3da = v3d1;
+        v3da += &v3d2;
+        assert_eq!(v3da, v3dr);
+        let mut v4da = v4d1;
+        v4da += v4d2;
+        assert_eq!(v4da, v4dr);
+    }
+
+    #[test]
+    fn vec_sub_op() {
+        let v2d1 = Vector2D::new(2, 4);
+        let v2d2 = Vector2D::new(-7, 2);
+        let v2dr = Vector2D::new(9, 2);
+
+        let v3d1 = Vector3D::new(2, 4, 5);
+        let v3d2 = Vector3D::new(-7, 2, 0);
+        let v3dr = Vector3D::new(9, 2, 5);
+
+        let v4d1 = Vector4D::new(2, 4, 5, 0);
+        let v4d2 = Vector4D::new(-7, 2, 0, -15);
+        let v4dr = Vector4D::new(9, 2, 5, 15);
+
+        assert_eq!(&v2d1 - &v2d2, v2dr);
+        assert_eq!(&v3d1 - &v3d2, v3dr);
+        assert_eq!(&v4d1 - &v4d2, v4dr);
+        assert_eq!(v2d1.clone() - v2d2.clone(), v2dr);
+
+        let mut v2da = v2d1;
+        v2da -= v2d2;
+        assert_eq!(v2da, v2dr);
+        let mut v3da = v3d1;
+        v3da -= &v3d2;
+        assert_eq!(v3da, v3dr);
+    }
+
+    #[test]
+    fn vec_neg_op() {
+        let v2d = Vector2D::new(5, -2);
+        let v3d = Vector3D::new(5, -2, 4);
+        let v4d = Vector4D::new(5, -2, 4, 900);
+
+        assert_eq!(-&v2d, v2d.invert());
+        assert_eq!(-&v3d, v3d.invert());
+        assert_eq!(-v4d.clone(), v4d.invert());
+    }
+
+    #[test]
+    fn vec_scale_op() {
+        let factor = 2;
+
+        let v2d = Vector2D::new(2, 4);
+        let v2dr = Vector2D::new(4, 8);
+
+        let v3d = Vector3D::new(2, 4, 0);
+        let v3dr = Vector3D::new(4, 8, 0);
+
+        let v4d = Vector4D::new(2, 4, 0, -3);
+        let v4dr = Vector4D::new(4, 8, 0, -6);
+
+        assert_eq!(&v2d * factor, v2dr);
+        assert_eq!(&v3d * factor, v3dr);
+        assert_eq!(v4d.clone() * factor, v4dr);
+
+        assert_eq!(&v2dr / factor, v2d);
+        assert_eq!(&v3dr / factor, v3d);
+        assert_eq!(v4dr.clone() / factor, v4d);
+
+        let mut v3da = v3d.clone();
+        v3da *= factor;
+        assert_eq!(v3da, v3dr);
+        v3da /= factor;
+        assert_eq!(v3da, v3d);
+    }
+
     #[test]
     fn vec_dot() {
         let v2d1 = Vector2D::new(2, 1);
@@ -247,6 +429,42 @@ mod tests {
         assert!(f32_eq(v4d.magnitude_f32(), v4drf32) && f64_eq(v4d.magnitude_f64(), v4drf64));
     }
 
+    #[test]
+    fn vec_magnitude_real() {
+        // not imported for the whole module, Dual's sin and cos would clash with Float's
+        use crate::types::RealScalar;
+
+        // computed in f64 all the way, going through f32 would round it
+        assert_eq!(Vector2D::new(0.1f64, 0.2).magnitude(), (0.1f64 * 0.1 + 0.2 * 0.2).sqrt());
+        // and too big for an f32 at all
+        assert_eq!(Vector3D::new(0., 1e100, 0.).magnitude(), 1e100);
+        assert_eq!(Vector2D::new(3f32, 4.).magnitude(), 5.);
+        assert!(f64_eq(DVector::new(vec![2., -3., 6.]).magnitude(), 7.));
+        assert!(DVector::new(vec![0., 0.]).normalized().is_none());
+        assert!(DVector::new(vec![0., 3., 4.]).normalized().unwrap().precision_eq(&DVector::new(vec![0., 0.6, 0.8]), 1e-12));
+
+        // the squares would overflow the integers themselves
+        let big = Vector2D::new(i64::MAX, i64::MAX);
+        assert!(f64_eq(big.magnitude_f64() / i64::MAX as f64, 2f64.sqrt()));
+        assert!(f32_eq(VectorN::new([u64::MAX; 4]).magnitude_f32() / u64::MAX as f32, 2.));
+
+        // the same generic code for floats and dual numbers
+        fn angle<T: RealScalar>(a: &Vector2D<T>, b: &Vector2D<T>) -> T {
+            (a.dot(b) / (a.magnitude() * b.magnitude())).acos()
+        }
+        let right = Vector2D::new(1., 0.);
+        assert!(f64_eq(angle(&right, &Vector2D::new(1., 1.)), std::f64::consts::FRAC_PI_4));
+        assert!(f32_eq(angle(&Vector2D::new(1f32, 0.), &Vector2D::new(0., 2.)), std::f32::consts::FRAC_PI_2));
+        // d/dy of the angle to (1, y) at y = 1 is 1 / (1 + y^2)
+        let dual_right = Vector2D::new(Dual::constant(1.), Dual::constant(0.));
+        let a = angle(&dual_right, &Vector2D::new(Dual::constant(1.), Dual::variable(1.)));
+        assert!(f64_eq(a.re, std::f64::consts::FRAC_PI_4) && f64_eq(a.eps, 0.5));
+        let n = Vector2D::new(Dual::variable(3.), Dual::constant(4.)).normalized().unwrap();
+        // d/dx of x / |(x, 4)| at x = 3 is 16 / 125
+        assert!(f64_eq(n.x.re, 0.6) && f64_eq(n.x.eps, 16. / 125.));
+        assert!(<f64 as RealScalar>::epsilon() == f64::EPSILON && <Dual<f64> as RealScalar>::epsilon().re == f64::EPSILON);
+    }
+
     #[test]
     fn vec_cross() {
         let v3_1 = Vector3D::new(1, 2, 3);
@@ -256,6 +474,115 @@ mod tests {
         assert_eq!(v3_1.cross(&v3_2), v3r);
     }
     
+    #[test]
+    fn vecn() {
+        let v5_1 = VectorN::new([1, 2, 3, 4, 5]);
+        let v5_2 = VectorN::new([5, 4, 3, 2, 1]);
+
+        assert_eq!(&v5_1 + &v5_2, VectorN::new([6; 5]));
+        assert_eq!(v5_1.sub(&v5_2), VectorN::new([-4, -2, 0, 2, 4]));
+        assert_eq!(v5_1.dot(&v5_2), 35);
+        assert_eq!(-v5_1.scale(2), VectorN::new([-2, -4, -6, -8, -10]));
+        assert_eq!(v5_1[4], 5);
+        assert!(f64_eq(VectorN::new([1, 1, 1, 1]).magnitude_f64(), 2.));
+        assert!(VectorN::<i32, 5>::from_slice(&[1, 2]).is_err());
+    }
+
+    #[test]
+    fn vecn_conversions() {
+        let v3 = Vector3D::new(1, 2, 3);
+        let vn: VectorN<i32, 3> = v3.clone().into();
+
+        assert_eq!(vn, VectorN::new([1, 2, 3]));
+        assert_eq!(Vector3D::from(vn), v3);
+        assert_eq!(Vector2D::from(VectorN::new([4, 5])), Vector2D::new(4, 5));
+        assert_eq!(VectorN::from(Vector4D::new(1, 2, 3, 4)).data, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn matmn_product() {
+        let m23 = MatrixMN::new([
+            [1, 2, 3],
+            [4, 5, 6]
+        ]);
+        let m32 = MatrixMN::new([
+            [7, 8],
+            [9, 10],
+            [11, 12]
+        ]);
+        let m22 = MatrixMN::new([
+            [58, 64],
+            [139, 154]
+        ]);
+
+        assert_eq!(m23.product(&m32), m22);
+        assert_eq!(&m23 * &m32, m22);
+        assert_eq!(m23.transposed(), MatrixMN::new([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(m23.mul_vec(&VectorN::new([1, 0, -1])), VectorN::new([-2, -2]));
+        assert_eq!(m23.dimensions(), (2, 3));
+    }
+
+    #[test]
+    fn matmn_square() {
+        let m5 = MatrixMN::new([
+            [0, 2, 1, 3, 0],
+            [1, 1, 0, 2, 1],
+            [0, 3, 1, 0, 2],
+            [1, 0, 4, 1, 1],
+            [2, 2, 1, 1, 0]
+        ]);
+        assert_eq!(m5.determinant(), 136);
+        assert_eq!(MatrixMN::<i32, 5, 5>::identity().determinant(), 1);
+
+        let m4 = Matrix4x4::new([
+            [1, 3, 1, 4],
+            [3, 9, 5, 15],
+            [0, 2, 1, 1],
+            [0, 4, 2, 3]
+        ]);
+        let m4n: MatrixMN<i32, 4, 4> = m4.clone().into();
+        assert_eq!(m4n.determinant(), m4.determinant());
+        assert_eq!(m4n.minor(3, 0), m4.minor(3, 0));
+        assert_eq!(Matrix4x4::from(m4n), m4);
+
+        let m3 = MatrixMN::new([
+            [1., 2., 3.],
+            [2., 8., -4.],
+            [5., 10., 2.]
+        ]);
+        assert!(m3.cross(&m3.inverse().unwrap()).precision_eq(&MatrixMN::identity(), 0.001));
+    }
+
+    #[test]
+    fn matmn_determinant_small_pivot() {
+        // a zero-only pivot test would divide by the 1e-20 and lose the rest of the matrix
+        let data = [
+            [1e-20, 1., 1.],
+            [1., 1., 0.],
+            [1., 0., 1.]
+        ];
+        let m = MatrixMN::new(data);
+        assert!(f64_eq(m.determinant(), -2.));
+        assert!(f64_eq(m.determinant(), m.lu().unwrap().determinant()));
+        assert!(f64_eq(m.determinant(), Matrix3x3::new(data).determinant()));
+        assert!(f64_eq(m.minor(1, 1), 1e-20 - 1.));
+    }
+
+    #[test]
+    fn mat_try_determinant_small_pivot() {
+        let m = Matrix3x3::new([[1e-20, 1., 1.], [1., 1., 0.], [1., 0., 1.]]);
+        assert!(f64_eq(m.try_determinant().unwrap(), -2.));
+        assert!(f64_eq(m.try_minor(1, 1).unwrap(), 1e-20 - 1.));
+        let inv = m.try_inverse().unwrap();
+        assert!((m.clone() * inv).precision_eq(&Matrix3x3::identity(), 1e-12));
+    }
+
+    #[test]
+    #[should_panic(expected = "Arithmetic overflow")]
+    fn matmn_determinant_overflow() {
+        MatrixMN::new([[i64::MAX, 1, 0], [1, i64::MAX, 0], [0, 0, 1]]).determinant();
+    }
+
     #[test]
     fn mat_zero() {
         let m3 = Matrix3x3::new([[0; 3]; 3]);
@@ -267,12 +594,19 @@ mod tests {
     
     #[test]
     fn mat_dimensions() {
+        assert_eq!(Matrix2x2::<i32>::zero().dimensions(), (2, 2));
         assert_eq!(Matrix3x3::<i32>::zero().dimensions(), (3, 3));
         assert_eq!(Matrix4x4::<i32>::zero().dimensions(), (4, 4));
     }
     
     #[test]
     fn mat_identity() {
+        let m2 = Matrix2x2::new(
+            [
+                [1, 0],
+                [0, 1],
+            ]
+        );
         let m3 = Matrix3x3::new(
             [
                 [1, 0, 0],
@@ -289,6 +623,7 @@ mod tests {
             ]
         );
 
+        assert_eq!(Matrix2x2::<i32>::identity(), m2);
         assert_eq!(Matrix3x3::<i32>::identity(), m3);
         assert_eq!(Matrix4x4::<i32>::identity(), m4);
     }
@@ -379,6 +714,7 @@ mod tests {
     
     #[test]
     fn mat_determinant() {
+        assert_eq!(Matrix2x2::<i32>::identity().determinant(), 1);
         assert_eq!(Matrix3x3::<i32>::identity().determinant(), 1);
         assert_eq!(Matrix4x4::<i32>::identity().determinant(), 1);
 
@@ -389,6 +725,12 @@ mod tests {
         ]);
         let m3_det = 49;
 
+        let m2 = Matrix2x2::new([
+            [3, 8],
+            [4, 6]
+        ]);
+        let m2_det = -14;
+
         let m4 = Matrix4x4::new([
             [1, 3, 1, 4],
             [3, 9, 5, 15],
@@ -397,6 +739,7 @@ mod tests {
         ]);
         let m4_det = -4;
 
+        assert_eq!(m2.determinant(), m2_det);
         assert_eq!(m3.determinant(), m3_det);
         assert_eq!(m4.determinant(), m4_det);
     }
@@ -419,10 +762,44 @@ mod tests {
             [10, 2, -4, 2]
         ]);
         assert_eq!(m4.minor(3, 0), 49);
+
+        let m2 = Matrix2x2::new([
+            [3, 8],
+            [4, 6]
+        ]);
+        assert_eq!(m2.minor(0, 0), 6);
+        assert_eq!(m2.minor(0, 1), 4);
+        assert_eq!(m2.minor(1, 0), 8);
+    }
+
+    #[test]
+    fn mat_minor_matrix() {
+        let m3 = Matrix3x3::new([
+            [2, -3, 1],
+            [2, 0, -1],
+            [1, 4, 5]
+        ]);
+        assert_eq!(m3.minor_matrix(0, 0), Matrix2x2::new([[0, -1], [4, 5]]));
+        assert_eq!(m3.minor_matrix(1, 0), Matrix2x2::new([[-3, 1], [4, 5]]));
+        assert_eq!(m3.minor_matrix(2, 1), Matrix2x2::new([[2, 1], [2, -1]]));
+
+        let m4 = Matrix4x4::new([
+            [1, 2, -3, 1],
+            [7, 2, 0, -1],
+            [-5, 1, 4, 5],
+            [10, 2, -4, 2]
+        ]);
+        let m4e = Matrix3x3::new([
+            [2, -3, 1],
+            [2, 0, -1],
+            [1, 4, 5]
+        ]);
+        assert_eq!(m4.minor_matrix(3, 0), m4e);
     }
     
     #[test]
     fn mat_transposed() {
+        assert_eq!(Matrix2x2::new([[1, 2], [3, 4]]).transposed(), Matrix2x2::new([[1, 3], [2, 4]]));
         assert_eq!(Matrix3x3::<i32>::identity().transposed(), Matrix3x3::<i32>::identity());
         assert_eq!(Matrix4x4::<i32>::identity().transposed(), Matrix4x4::<i32>::identity());
 
@@ -577,8 +954,84 @@ mod tests {
         assert_eq!(m4_1.cross(&m4_2), m4r);
     }
     
+    #[test]
+    fn mat_ops() {
+        let m3_1 = Matrix3x3::new([
+            [1, 2, 3],
+            [3, 2, 1],
+            [8, 0, -5]
+        ]);
+        let m3_2 = Matrix3x3::new([
+            [0, 2, 1],
+            [1, 2, 3],
+            [-5, 4, 8]
+        ]);
+
+        assert_eq!(&m3_1 + &m3_2, m3_1.add(&m3_2));
+        assert_eq!(&m3_1 - &m3_2, m3_1.sub(&m3_2));
+        assert_eq!(&m3_1 * &m3_2, m3_1.cross(&m3_2));
+        assert_eq!(m3_1.clone() * m3_2.clone(), m3_1.cross(&m3_2));
+        assert_eq!(&m3_1 * 2, m3_1.add(&m3_1));
+
+        let m4 = Matrix4x4::new([
+            [1, 0, 2, 0],
+            [7, 1, -1, -3],
+            [5, 0, 2, -1],
+            [3, 3, 4, 2]
+        ]);
+        let v4 = Vector4D::new(0, 1, -2, 2);
+        let r4 = Vector4D::new(-4, -3, -6, -1);
+
+        assert_eq!(&m4 * &v4, r4);
+        assert_eq!(m4.clone() * v4, r4);
+        assert_eq!(m4.clone() + m4.clone(), m4.clone() * 2);
+        assert_eq!(m4.clone() - m4.clone(), Matrix4x4::zero());
+    }
+
+    #[test]
+    fn mat_index() {
+        let mut m3 = Matrix3x3::new([
+            [5, 2, 6],
+            [7, 9, -1],
+            [2, 5, 1]
+        ]);
+        let mut m4 = Matrix4x4::<i32>::identity();
+
+        assert_eq!(m3[(1, 2)], -1);
+        assert_eq!(m3[(2, 0)], 2);
+        assert_eq!(m4[(3, 3)], 1);
+
+        m3[(1, 2)] = 4;
+        m4[(0, 3)] = 8;
+        assert_eq!(m3.get_val(1, 2), 4);
+        assert_eq!(m4.get_val(0, 3), 8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mat_index_out_of_range() {
+        let m3 = Matrix3x3::<i32>::identity();
+        let _ = m3[(0, 3)];
+    }
+
     #[test]
     fn mat_inverse() {
+        let i2 = Matrix2x2::<f32>::identity();
+        let m2 = Matrix2x2::new([
+            [4., 7.],
+            [2., 6.]
+        ]);
+        let m2e = Matrix2x2::new([
+            [0.6, -0.7],
+            [-0.2, 0.4]
+        ]);
+        assert!(m2.inverse().unwrap().precision_eq(&m2e, 0.0001));
+        assert!(m2.cross(&m2.inverse().unwrap()).precision_eq(&i2, 0.001));
+        assert!(Matrix2x2::new([[1., 2.], [2., 4.]]).inverse().is_none());
+        // the determinant underflows to zero but the matrix is perfectly conditioned
+        let tiny = Matrix2x2::new([[1e-200f64, 0.], [0., 1e-200]]);
+        assert_eq!(tiny.try_inverse().unwrap(), Matrix2x2::new([[1e200, 0.], [0., 1e200]]));
+
         let i3 = Matrix3x3::<f32>::identity();
         let i4 = Matrix4x4::<f32>::identity();
         assert_eq!(Matrix3x3::<f32>::identity().inverse().unwrap(), i3);
@@ -601,6 +1054,14 @@ mod tests {
     
     #[test]
     fn mat_mul_vec() {
+        let m2 = Matrix2x2::new([
+            [0, -1],
+            [1, 0]
+        ]);
+        let v2 = Vector2D::new(3, 2);
+        let r2 = Vector2D::new(-2, 3);
+        assert_eq!(m2.mul_vec(&v2), r2);
+
         let m3 = Matrix3x3::new([
             [1, 2, 0],
             [-2, 5, 1],
@@ -621,4 +1082,1190 @@ mod tests {
         assert_eq!(m3.mul_vec(&v3), r3);
         assert_eq!(m4.mul_vec(&v4), r4);
     }
+
+    #[test]
+    fn mat_rectangular() {
+        let m34 = Matrix3x4::new([
+            [1, 0, 0, 5],
+            [0, 1, 0, -2],
+            [0, 0, 1, 3]
+        ]);
+        let v4 = Vector4D::new(1, 2, 3, 1);
+        let v3: Vector3D<i32> = m34.mul_vec(&v4);
+
+        assert_eq!(v3, Vector3D::new(6, 0, 6));
+        assert_eq!(&m34 * &v4, v3);
+        assert_eq!(m34.dimensions(), (3, 4));
+
+        let m43: Matrix4x3<i32> = m34.transposed();
+        assert_eq!(m43.dimensions(), (4, 3));
+        assert_eq!(m43[(3, 0)], 5);
+        assert_eq!(m43.mul_vec(&Vector3D::new(1, 1, 1)), Vector4D::new(1, 1, 1, 6));
+
+        let m33: Matrix3x3<i32> = m34.product(&m43);
+        assert_eq!(m33, Matrix3x3::new([
+            [26, -10, 15],
+            [-10, 5, -6],
+            [15, -6, 10]
+        ]));
+        let m34_2: Matrix3x4<i32> = m34.product(&Matrix4x4::identity());
+        assert_eq!(m34_2, m34);
+
+        let m23 = Matrix2x3::new([
+            [1, 2, 3],
+            [4, 5, 6]
+        ]);
+        let m32 = Matrix3x2::new([
+            [7, 8],
+            [9, 10],
+            [11, 12]
+        ]);
+        let m22: Matrix2x2<i32> = m23.product(&m32);
+        assert_eq!(m22, Matrix2x2::new([[58, 64], [139, 154]]));
+        assert_eq!(m23.transposed(), Matrix3x2::new([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(&m23 + &m23, &m23 * 2);
+    }
+
+    #[test]
+    fn dvec_ops() {
+        let v1 = DVector::new(vec![2, 1, 5, -2, 3]);
+        let v2 = DVector::new(vec![-7, 2, 5, 6, 0]);
+
+        assert_eq!(v1.add(&v2).unwrap(), DVector::new(vec![-5, 3, 10, 4, 3]));
+        assert_eq!(v1.sub(&v2).unwrap(), DVector::new(vec![9, -1, 0, -8, 3]));
+        assert_eq!(v1.dot(&v2).unwrap(), 1);
+        assert_eq!(v1.scale(2), DVector::new(vec![4, 2, 10, -4, 6]));
+        assert_eq!(v1.invert()[3], 2);
+        assert!(f64_eq(DVector::new(vec![2, -3, 6]).magnitude_f64(), 7.));
+
+        let short = DVector::new(vec![1, 2]);
+        assert!(v1.add(&short).is_err());
+        assert!(v1.dot(&short).is_err());
+        assert!(!v1.precision_eq(&short, 10));
+    }
+
+    #[test]
+    fn dmat_ops() {
+        let m23 = DMatrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let m32 = DMatrix::from_vec(3, 2, vec![7, 8, 9, 10, 11, 12]).unwrap();
+        let m22 = DMatrix::from_vec(2, 2, vec![58, 64, 139, 154]).unwrap();
+
+        assert_eq!(m23.product(&m32).unwrap(), m22);
+        assert_eq!(m23.transposed().dimensions(), (3, 2));
+        assert_eq!(m23.transposed()[(2, 1)], 6);
+        assert_eq!(m23.add(&m23).unwrap(), m23.scale(2));
+        assert_eq!(m23.sub(&m23).unwrap(), DMatrix::zero(2, 3));
+        assert_eq!(m23.mul_vec(&DVector::new(vec![1, 0, -1])).unwrap(), DVector::new(vec![-2, -2]));
+
+        assert!(m23.product(&m23).is_err());
+        assert!(m23.add(&m32).is_err());
+        assert!(m23.mul_vec(&DVector::new(vec![1, 2])).is_err());
+        assert!(m23.determinant().is_err());
+        assert!(m23.inverse().is_err());
+        assert!(DMatrix::from_vec(2, 2, vec![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn dmat_determinant_inverse() {
+        let m4 = Matrix4x4::new([
+            [1f64, 2., 3., 2.],
+            [2., 8., -4., 3.],
+            [5., 10., 2., 0.],
+            [8., 0., 2., 4.]
+        ]);
+        let d4 = DMatrix::from(m4.clone());
+
+        assert!((d4.determinant().unwrap() - m4.determinant()).abs() < 0.0001);
+        let inv = d4.inverse().unwrap();
+        assert!(d4.product(&inv).unwrap().precision_eq(&DMatrix::identity(4), 0.0001));
+        assert!(matches!(DMatrix::from_vec(2, 2, vec![1., 2., 2., 4.]).unwrap().inverse(), Err(MatrixErr::Singular)));
+
+        // pivoting instead of only stepping over exact zeros
+        let small_pivot = DMatrix::from_vec(3, 3, vec![1e-20, 1., 1., 1., 1., 0., 1., 0., 1.]).unwrap();
+        assert!(f64_eq(small_pivot.determinant().unwrap(), -2.));
+
+        // integer matrices go through cofactors like the fixed-size ones
+        let unimodular = DMatrix::from_vec(2, 2, vec![2, 1, 1, 1]).unwrap();
+        assert_eq!(unimodular.inverse().unwrap(), DMatrix::from_vec(2, 2, vec![1, -1, -1, 2]).unwrap());
+        assert!(matches!(DMatrix::<i32>::identity(2).scale(2).inverse(), Err(MatrixErr::NotExact)));
+        assert!(matches!(DMatrix::from_vec(2, 2, vec![1, 2, 2, 4]).unwrap().inverse(), Err(MatrixErr::Singular)));
+        let m3 = Matrix3x3::new([[1, 2, 0], [0, 1, 0], [0, 0, 1]]);
+        assert_eq!(DMatrix::from(m3.clone()).inverse().unwrap(), DMatrix::from(m3.try_inverse().unwrap()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (0, 2) out of range")]
+    fn dmat_get_val_out_of_range() {
+        DMatrix::<i32>::identity(2).get_val(0, 2);
+    }
+
+    #[test]
+    fn dynamic_conversions() {
+        let v3 = Vector3D::new(1, 2, 3);
+        let d3 = DVector::from(v3.clone());
+        assert_eq!(d3.as_slice(), &[1, 2, 3]);
+        assert_eq!(Vector3D::try_from(d3.clone()).unwrap(), v3);
+        assert!(Vector4D::try_from(d3).is_err());
+
+        let m2 = Matrix2x2::new([[1, 2], [3, 4]]);
+        let dm2 = DMatrix::from(m2.clone());
+        assert_eq!(dm2.dimensions(), (2, 2));
+        assert_eq!(Matrix2x2::try_from(dm2.clone()).unwrap(), m2);
+        assert!(Matrix3x3::try_from(dm2).is_err());
+
+        let mn = MatrixMN::new([[1, 2, 3]]);
+        let dmn = DMatrix::from(mn.clone());
+        assert_eq!(MatrixMN::<i32, 1, 3>::try_from(dmn).unwrap(), mn);
+    }
+
+    #[test]
+    fn quat_hamilton() {
+        let i = Quaternion::new(0., 1., 0., 0.);
+        let j = Quaternion::new(0., 0., 1., 0.);
+        let k = Quaternion::new(0., 0., 0., 1.);
+        let minus_one = Quaternion::new(-1., 0., 0., 0.);
+
+        assert_eq!(&i * &j, k);
+        assert_eq!(&j * &i, Quaternion::new(0., 0., 0., -1.));
+        assert_eq!(&i * &i, minus_one);
+        assert_eq!(i.clone() * j.clone() * k.clone(), minus_one);
+
+        let q = Quaternion::new(1., 2., 3., 4.);
+        assert_eq!(q.conjugate(), Quaternion::new(1., -2., -3., -4.));
+        assert!((&q * &q.inverse().unwrap()).precision_eq(&Quaternion::identity(), 1e-12));
+        assert!(f64_eq(q.normalized().magnitude(), 1.));
+        assert!(Quaternion::new(0., 0., 0., 0.).inverse().is_none());
+        let small = Quaternion::new(1e-9, 0., 0., 0.);
+        assert!(small.inverse().unwrap().precision_eq(&Quaternion::new(1e9, 0., 0., 0.), 1e-3));
+        assert!(Quaternion::new(f64::INFINITY, 0., 0., 0.).inverse().is_none());
+    }
+
+    #[test]
+    fn quat_rotation() {
+        let half_pi = std::f64::consts::FRAC_PI_2;
+        let qz = Quaternion::from_axis_angle(&Vector3D::new(0., 0., 2.), half_pi);
+        let rotated = qz.rotate(&Vector3D::new(1., 0., 0.));
+        assert!(rotated.precision_eq(&Vector3D::new(0., 1., 0.), 1e-12));
+
+        let qx = Quaternion::from_axis_angle(&Vector3D::new(1., 0., 0.), half_pi);
+        let v = Vector3D::new(1., 2., 3.);
+        // rotating about z then x is the same as rotating by the product
+        let composed = (&qx * &qz).rotate(&v);
+        assert!(composed.precision_eq(&qx.rotate(&qz.rotate(&v)), 1e-12));
+        assert!((qx.clone() * v.clone()).precision_eq(&Vector3D::new(1., -3., 2.), 1e-12));
+
+        let (axis, angle) = qx.to_axis_angle();
+        assert!(axis.precision_eq(&Vector3D::new(1., 0., 0.), 1e-12));
+        assert!(f64_eq(angle, half_pi));
+    }
+
+    #[test]
+    fn quat_interpolation() {
+        let pi = std::f64::consts::PI;
+        let axis = Vector3D::new(0., 1., 0.);
+        let q0 = Quaternion::identity();
+        let q1 = Quaternion::from_axis_angle(&axis, pi / 2.);
+
+        assert!(q0.slerp(&q1, 0.).precision_eq(&q0, 1e-12));
+        assert!(q0.slerp(&q1, 1.).precision_eq(&q1, 1e-12));
+        assert!(q0.slerp(&q1, 0.5).precision_eq(&Quaternion::from_axis_angle(&axis, pi / 4.), 1e-12));
+        assert!(f64_eq(q0.nlerp(&q1, 0.3).magnitude(), 1.));
+        assert!(q0.nlerp(&q1, 0.5).precision_eq(&q0.slerp(&q1, 0.5), 1e-12));
+
+        // the negated quaternion is the same rotation, slerp must take the short way
+        let q1_neg = Quaternion::new(-q1.w, -q1.x, -q1.y, -q1.z);
+        assert!(q0.slerp(&q1_neg, 0.5).precision_eq(&q0.slerp(&q1, 0.5), 1e-12));
+    }
+
+    #[test]
+    fn quat_matrix_conversions() {
+        let axes = [
+            Vector3D::new(1., 0., 0.),
+            Vector3D::new(0., 1., 0.),
+            Vector3D::new(0., 0., 1.),
+            Vector3D::new(1., -2., 0.5),
+        ];
+        let v = Vector3D::new(0.3, -1.2, 2.5);
+        for axis in axes.iter() {
+            for angle in [0.1, 1.5, 3.0, -2.2] {
+                let q = Quaternion::from_axis_angle(axis, angle);
+                let m3 = q.to_matrix3x3();
+                let m4 = Matrix4x4::from(q.clone());
+
+                assert!(m3.mul_vec(&v).precision_eq(&q.rotate(&v), 1e-12));
+                assert!(m4.get_val(3, 3) == 1. && m4.get_val(0, 3) == 0.);
+
+                let back = Quaternion::from(m3);
+                let same = back.precision_eq(&q, 1e-12)
+                    || back.precision_eq(&Quaternion::new(-q.w, -q.x, -q.y, -q.z), 1e-12);
+                assert!(same);
+                let back4 = Quaternion::from_matrix4x4(&m4);
+                assert!(back4.to_matrix3x3().precision_eq(&q.to_matrix3x3(), 1e-12));
+            }
+        }
+    }
+
+    #[test]
+    fn transform_translation_scaling() {
+        let t = Matrix4x4::translation(&Vector3D::new(1, -2, 3));
+        let s = Matrix4x4::scaling(&Vector3D::new(2, 3, 4));
+        let p = Vector3D::new(1, 1, 1);
+
+        assert_eq!(t.transform_point(&p).unwrap(), Vector3D::new(2, -1, 4));
+        assert_eq!(t.transform_vector(&p), p);
+        assert_eq!(s.transform_point(&p).unwrap(), Vector3D::new(2, 3, 4));
+        assert_eq!((&t * &s).transform_point(&p).unwrap(), Vector3D::new(3, 1, 7));
+        assert_eq!((&s * &t).transform_point(&p).unwrap(), Vector3D::new(4, -3, 16));
+    }
+
+    #[test]
+    fn transform_shear() {
+        let sh = Matrix4x4::shear(2, 0, 0, 0, 0, 1);
+        assert_eq!(sh.transform_point(&Vector3D::new(1, 1, 1)).unwrap(), Vector3D::new(3, 1, 2));
+        assert_eq!(sh.transform_vector(&Vector3D::new(0, 0, 5)), Vector3D::new(0, 0, 5));
+    }
+
+    #[test]
+    fn transform_rotations() {
+        let half_pi = std::f64::consts::FRAC_PI_2;
+        let x = Vector3D::new(1., 0., 0.);
+        let y = Vector3D::new(0., 1., 0.);
+        let z = Vector3D::new(0., 0., 1.);
+
+        assert!(Matrix4x4::rotation_x(half_pi).transform_vector(&y).precision_eq(&z, 1e-12));
+        assert!(Matrix4x4::rotation_y(half_pi).transform_vector(&z).precision_eq(&x, 1e-12));
+        assert!(Matrix4x4::rotation_z(half_pi).transform_vector(&x).precision_eq(&y, 1e-12));
+
+        for angle in [0.3, -1.2, 2.9] {
+            assert!(Matrix4x4::rotation_axis(&x, angle).precision_eq(&Matrix4x4::rotation_x(angle), 1e-12));
+            assert!(Matrix4x4::rotation_axis(&y, angle).precision_eq(&Matrix4x4::rotation_y(angle), 1e-12));
+            assert!(Matrix4x4::rotation_axis(&z.scale(3.), angle).precision_eq(&Matrix4x4::rotation_z(angle), 1e-12));
+        }
+
+        let r = Matrix4x4::rotation_axis(&Vector3D::new(1., 1., 1.), 2. * std::f64::consts::FRAC_PI_3);
+        assert!(r.transform_point(&x).unwrap().precision_eq(&y, 1e-12));
+    }
+
+    #[test]
+    fn transform_point_perspective_divide() {
+        let mut m = Matrix4x4::<f64>::identity();
+        m[(3, 2)] = 0.5;
+        let p = m.transform_point(&Vector3D::new(2., 4., 4.)).unwrap();
+        assert!(p.precision_eq(&Vector3D::new(2. / 3., 4. / 3., 4. / 3.), 1e-12));
+        // w = 0 is a point at infinity, there is nothing finite to return
+        m[(3, 3)] = 0.;
+        assert!(m.transform_point(&Vector3D::new(2., 4., 0.)).is_none());
+        assert!(m.transform_point(&Vector3D::new(2., 4., 2.)).is_some());
+        // directions never get divided
+        assert_eq!(m.transform_vector(&Vector3D::new(2., 4., 4.)), Vector3D::new(2., 4., 4.));
+    }
+
+    fn project(m: &Matrix4x4<f64>, p: &Vector3D<f64>) -> Vector3D<f64> {
+        let c = m.mul_vec(&Vector4D::new(p.x, p.y, p.z, 1.));
+        Vector3D::new(c.x / c.w, c.y / c.w, c.z / c.w)
+    }
+
+    #[test]
+    fn camera_look_at() {
+        let eye = Vector3D::new(1., 2., 3.);
+        let target = Vector3D::new(1., 2., -7.);
+        let up = Vector3D::new(0., 1., 0.);
+
+        let rh = Matrix4x4::look_at(&eye, &target, &up, Handedness::Right);
+        assert!(project(&rh, &eye).precision_eq(&Vector3D::zero(), 1e-12));
+        assert!(project(&rh, &target).precision_eq(&Vector3D::new(0., 0., -10.), 1e-12));
+        assert!(project(&rh, &Vector3D::new(2., 2., 3.)).precision_eq(&Vector3D::new(1., 0., 0.), 1e-12));
+        assert!(project(&rh, &Vector3D::new(1., 3., 3.)).precision_eq(&Vector3D::new(0., 1., 0.), 1e-12));
+
+        let lh = Matrix4x4::look_at(&eye, &target, &up, Handedness::Left);
+        assert!(project(&lh, &target).precision_eq(&Vector3D::new(0., 0., 10.), 1e-12));
+        assert!(project(&lh, &Vector3D::new(1., 3., 3.)).precision_eq(&Vector3D::new(0., 1., 0.), 1e-12));
+
+        // the view matrix of a rigid camera is orthonormal
+        let r3 = rh.minor_matrix(3, 3);
+        assert!(r3.cross(&r3.transposed()).precision_eq(&Matrix3x3::identity(), 1e-12));
+
+        // up along the view direction still gives a rigid camera instead of a flattened one
+        let along_x = Vector3D::new(1., 0., 0.);
+        for (hand, sz) in [(Handedness::Right, -1.), (Handedness::Left, 1.)] {
+            let m = Matrix4x4::look_at(&Vector3D::zero(), &along_x, &along_x, hand);
+            assert!((m.determinant().abs() - 1.).abs() < 1e-12);
+            let r3 = m.minor_matrix(3, 3);
+            assert!(r3.cross(&r3.transposed()).precision_eq(&Matrix3x3::identity(), 1e-12));
+            assert!(project(&m, &along_x).precision_eq(&Vector3D::new(0., 0., sz), 1e-12));
+        }
+    }
+
+    #[test]
+    fn camera_perspective() {
+        let fov = std::f64::consts::FRAC_PI_2;
+        let (near, far) = (0.5, 100.);
+        let ranges = [
+            (DepthRange::NegOneToOne, -1., 1.),
+            (DepthRange::ZeroToOne, 0., 1.),
+            (DepthRange::ReverseZ, 1., 0.),
+        ];
+
+        for (depth, n0, f0) in ranges {
+            for (hand, sz) in [(Handedness::Right, -1.), (Handedness::Left, 1.)] {
+                let m = Matrix4x4::perspective(fov, 2., near, far, hand, depth);
+
+                let pn = project(&m, &Vector3D::new(0., 0., sz * near));
+                let pf = project(&m, &Vector3D::new(0., 0., sz * far));
+                assert!((pn.z - n0).abs() < 1e-9 && (pf.z - f0).abs() < 1e-9);
+
+                // with a 90 degree fov the frustum edge at distance d is at y = d, x = aspect * d
+                let corner = project(&m, &Vector3D::new(2. * 10., 10., sz * 10.));
+                assert!((corner.x - 1.).abs() < 1e-9 && (corner.y - 1.).abs() < 1e-9);
+
+                let mid = project(&m, &Vector3D::new(0., 0., sz * 10.));
+                assert!(mid.z > n0.min(f0) && mid.z < n0.max(f0));
+            }
+        }
+    }
+
+    #[test]
+    fn camera_perspective_infinite() {
+        let fov = std::f64::consts::FRAC_PI_3;
+        let near = 0.1;
+        let m = Matrix4x4::perspective_infinite(fov, 1., near, Handedness::Right, DepthRange::ReverseZ);
+
+        assert!((project(&m, &Vector3D::new(0., 0., -near)).z - 1.).abs() < 1e-9);
+        assert!(project(&m, &Vector3D::new(0., 0., -1e12)).z.abs() < 1e-9);
+
+        let gl = Matrix4x4::perspective_infinite(fov, 1., near, Handedness::Right, DepthRange::NegOneToOne);
+        assert!((project(&gl, &Vector3D::new(0., 0., -near)).z + 1.).abs() < 1e-9);
+        assert!((project(&gl, &Vector3D::new(0., 0., -1e12)).z - 1.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn camera_orthographic() {
+        let (l, r, b, t, n, f) = (-4., 2., -1., 3., 1., 11.);
+        for (depth, n0, f0) in [(DepthRange::NegOneToOne, -1., 1.), (DepthRange::ZeroToOne, 0., 1.), (DepthRange::ReverseZ, 1., 0.)] {
+            for (hand, sz) in [(Handedness::Right, -1.), (Handedness::Left, 1.)] {
+                let m = Matrix4x4::orthographic(l, r, b, t, n, f, hand, depth);
+
+                let lo = project(&m, &Vector3D::new(l, b, sz * n));
+                let hi = project(&m, &Vector3D::new(r, t, sz * f));
+                assert!(lo.precision_eq(&Vector3D::new(-1., -1., n0), 1e-12));
+                assert!(hi.precision_eq(&Vector3D::new(1., 1., f0), 1e-12));
+            }
+        }
+    }
+
+    #[test]
+    fn lu_decomposition() {
+        let m = Matrix3x3::new([[1f64, 2., 3.], [4., 5., 6.], [7., 8., 10.]]);
+        let lu = m.lu().unwrap();
+
+        assert!(!lu.is_singular());
+        assert_eq!(lu.permutation(), &[2, 0, 1]);
+        assert!(lu.sign() == 1.);
+        assert!((lu.p() * m.clone()).precision_eq(&(lu.l() * lu.u()), 1e-12));
+        assert!((lu.determinant() - m.determinant()).abs() < 1e-12);
+
+        let x = lu.solve(&Vector3D::new(6., 15., 25.)).unwrap();
+        assert!(x.precision_eq(&Vector3D::new(1., 1., 1.), 1e-12));
+        assert!((m.clone() * m.inverse().unwrap()).precision_eq(&Matrix3x3::identity(), 1e-12));
+    }
+
+    #[test]
+    fn lu_singular() {
+        let m = Matrix3x3::new([[1f64, 2., 3.], [2., 4., 6.], [1., 0., 1.]]);
+        let lu = LU::new(&m).unwrap();
+        assert!(lu.is_singular());
+        assert!(lu.solve(&Vector3D::new(1., 2., 3.)).is_none());
+        assert!(m.inverse().is_none());
+
+        // nearly dependent rows still count as singular once rounding is accounted for
+        let m = MatrixMN::new([[0.1f64, 0.2], [0.3, 0.6 + 1e-17]]);
+        assert!(m.lu().unwrap().is_singular());
+
+        let m5 = MatrixMN::<f64, 5, 5>::from_func(|i, j| 1. / (i + j + 1) as f64);
+        let inv = m5.inverse().unwrap();
+        assert!(m5.product(&inv).precision_eq(&MatrixMN::identity(), 1e-9));
+    }
+
+    #[test]
+    fn lu_integers() {
+        // truncating the elimination factors would give det 2 and x = (0, 0)
+        let m = Matrix2x2::new([[2i32, 1], [1, 1]]);
+        assert!(matches!(m.lu(), Err(MatrixErr::NotAField)));
+        assert_eq!(m.determinant(), 1);
+        assert_eq!(m.solve(&Vector2D::new(1, 0)).unwrap(), Vector2D::new(1, -1));
+        assert_eq!(m.inverse().unwrap(), Matrix2x2::new([[1, -1], [-1, 2]]));
+    }
+
+    #[test]
+    fn mat_solve() {
+        let m = Matrix3x3::new([[2f64, 1., -1.], [-3., -1., 2.], [-2., 1., 2.]]);
+        let x = m.solve(&Vector3D::new(8., -11., -3.)).unwrap();
+        assert!(x.precision_eq(&Vector3D::new(2., 3., -1.), 1e-12));
+
+        // integer systems with integer solutions come out exact
+        let mi = Matrix3x3::new([[2, 1, -1], [-3, -1, 2], [-2, 1, 2]]);
+        assert_eq!(mi.solve(&Vector3D::new(8, -11, -3)).unwrap(), Vector3D::new(2, 3, -1));
+
+        let b = Matrix3x2::new([[8., 1.], [-11., 0.], [-3., 0.]]);
+        let xs = m.solve_matrix(&b).unwrap();
+        let check: Matrix3x2<f64> = m.product(&xs);
+        assert!(check.precision_eq(&b, 1e-12));
+        assert!(xs.precision_eq(&Matrix3x2::new([[2., 4.], [3., -2.], [-1., 5.]]), 1e-12));
+
+        let mn = MatrixMN::new([[4f64, 3.], [6., 3.]]);
+        let x = mn.solve(&VectorN::new([10., 12.])).unwrap();
+        assert!(x.precision_eq(&VectorN::new([1., 2.]), 1e-12));
+    }
+
+    #[test]
+    fn mat_solve_errors() {
+        let singular = Matrix2x2::new([[1f64, 2.], [2., 4.]]);
+        assert!(matches!(singular.solve(&Vector2D::new(1., 1.)), Err(MatrixErr::Singular)));
+        let singular_int = Matrix2x2::new([[1, 2], [2, 4]]);
+        assert!(matches!(singular_int.solve(&Vector2D::new(1, 1)), Err(MatrixErr::Singular)));
+        // the solution needs fractions, truncating them would be a wrong answer
+        let twice = Matrix2x2::new([[2, 0], [0, 2]]);
+        assert!(matches!(twice.solve(&Vector2D::new(1, 1)), Err(MatrixErr::NotExact)));
+        assert!(matches!(twice.try_inverse(), Err(MatrixErr::NotExact)));
+        assert!(matches!(Matrix3x3::new([[2, 0, 0], [0, 2, 0], [0, 0, 2]]).try_inverse(), Err(MatrixErr::NotExact)));
+        assert_eq!(twice.solve(&Vector2D::new(4, -2)).unwrap(), Vector2D::new(2, -1));
+
+        let ill = Matrix2x2::new([[1f64, 1.], [1., 1. + 1e-15]]);
+        assert!(!ill.lu().unwrap().is_singular());
+        assert!(matches!(ill.solve(&Vector2D::new(2., 2.)), Err(MatrixErr::IllConditioned)));
+        assert!(Matrix2x2::new([[1f64, 1.], [1., 1.001]]).solve(&Vector2D::new(2., 2.)).is_ok());
+    }
+
+    #[test]
+    fn qr_decomposition() {
+        let m = Matrix3x3::new([[12f64, -51., 4.], [6., 167., -68.], [-4., 24., -41.]]);
+        let qr = m.qr();
+        let (q, r) = (qr.q(), qr.r());
+
+        assert!((q.transposed() * q.clone()).precision_eq(&Matrix3x3::identity(), 1e-12));
+        assert!((q * r.clone()).precision_eq(&m, 1e-12));
+        assert!(r.precision_eq(&Matrix3x3::new([[-14., -21., 14.], [0., -175., 70.], [0., 0., -35.]]), 1e-9));
+
+        let m4 = Matrix4x4::new([
+            [1f64, 2., 3., 2.],
+            [2., 8., -4., 3.],
+            [5., 10., 2., 0.],
+            [8., 0., 2., 4.]
+        ]);
+        let b = Vector4D::new(1., 2., 3., 4.);
+        let x = QR::new(&m4).solve_least_squares(&b).unwrap();
+        assert!(x.precision_eq(&m4.solve(&b).unwrap(), 1e-12));
+        assert!(matches!(
+            Matrix2x2::new([[1f64, 2.], [2., 4.]]).qr().solve_least_squares(&Vector2D::new(1., 1.)),
+            Err(MatrixErr::Singular)
+        ));
+    }
+
+    #[test]
+    fn qr_least_squares() {
+        // fit y = a + b x through slightly noisy points
+        let a = DMatrix::from_vec(4, 2, vec![1., 0., 1., 1., 1., 2., 1., 3.]).unwrap();
+        let y = DVector::new(vec![1.1, 2.9, 5.1, 6.9]);
+        let (q, r) = a.qr();
+        assert_eq!(q.dimensions(), (4, 4));
+        assert!(q.transposed().product(&q).unwrap().precision_eq(&DMatrix::identity(4), 1e-12));
+        assert!(q.product(&r).unwrap().precision_eq(&a, 1e-12));
+
+        let fit = a.solve_least_squares(&y).unwrap();
+        assert!(fit.precision_eq(&DVector::new(vec![1.06, 1.96]), 1e-12));
+        assert!(matches!(
+            a.solve_least_squares(&DVector::zero(3)),
+            Err(MatrixErr::DimensionErr { expected: (4, 1), got: (3, 1) })
+        ));
+        assert!(matches!(
+            a.transposed().solve_least_squares(&DVector::zero(2)),
+            Err(MatrixErr::DimensionErr { expected: (4, 4), got: (2, 4) })
+        ));
+        let collinear = DMatrix::from_vec(3, 2, vec![1., 2., 2., 4., 3., 6.]).unwrap();
+        assert!(matches!(collinear.solve_least_squares(&DVector::zero(3)), Err(MatrixErr::Singular)));
+    }
+
+    #[test]
+    fn eigen_symmetric() {
+        let m = Matrix3x3::new([[2f64, -1., 0.], [-1., 2., -1.], [0., -1., 2.]]);
+        let (values, vectors) = m.symmetric_eigen();
+        let r2 = 2f64.sqrt();
+        assert!(values.precision_eq(&Vector3D::new(2. - r2, 2., 2. + r2), 1e-12));
+
+        let expected = [
+            Vector3D::new(0.5, r2 / 2., 0.5),
+            Vector3D::new(r2 / 2., 0., -r2 / 2.),
+            Vector3D::new(0.5, -r2 / 2., 0.5),
+        ];
+        for (j, e) in expected.iter().enumerate() {
+            let col = Vector3D::new(vectors[(0, j)], vectors[(1, j)], vectors[(2, j)]);
+            // eigenvectors are only defined up to sign
+            assert!((col.dot(e).abs() - 1.).abs() < 1e-12);
+        }
+
+        let diag = Matrix3x3::new([[3f64, 0., 0.], [0., -1., 0.], [0., 0., 2.]]);
+        let (values, vectors) = diag.symmetric_eigen();
+        assert_eq!(values, Vector3D::new(-1., 2., 3.));
+        assert_eq!(vectors, Matrix3x3::new([[0., 0., 1.], [1., 0., 0.], [0., 1., 0.]]));
+    }
+
+    #[test]
+    fn eigen_reconstruct() {
+        // covariance-like matrix in f32
+        let m = Matrix3x3::new([[4f32, 1.5, -0.7], [1.5, 3., 0.25], [-0.7, 0.25, 1.]]);
+        let (values, vectors) = m.symmetric_eigen();
+        assert!(values.x <= values.y && values.y <= values.z);
+        assert!((vectors.transposed() * vectors.clone()).precision_eq(&Matrix3x3::identity(), 1e-5));
+
+        let lambda = Matrix3x3::new([[values.x, 0., 0.], [0., values.y, 0.], [0., 0., values.z]]);
+        let back = vectors.clone() * lambda * vectors.transposed();
+        assert!(back.precision_eq(&m, 1e-5));
+        assert!((values.x + values.y + values.z - 8.).abs() < 1e-5);
+        assert!((values.x * values.y * values.z - m.determinant()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn svd_decomposition() {
+        let m4 = Matrix4x4::new([
+            [1f64, 2., 3., 2.],
+            [2., 8., -4., 3.],
+            [5., 10., 2., 0.],
+            [8., 0., 2., 4.]
+        ]);
+        let svd = m4.svd();
+        let (u, v_t) = (svd.u(), svd.v_t());
+        assert!((u.transposed() * u.clone()).precision_eq(&Matrix4x4::identity(), 1e-12));
+        assert!((v_t.clone() * v_t.transposed()).precision_eq(&Matrix4x4::identity(), 1e-12));
+        assert!((u * svd.sigma() * v_t).precision_eq(&m4, 1e-10));
+
+        let s = svd.singular_values();
+        assert!(s.x >= s.y && s.y >= s.z && s.z >= s.w && s.w > 0.);
+        // the squared singular values add up to the squared frobenius norm
+        let frobenius: f64 = (0..16).map(|k| m4[(k / 4, k % 4)].powi(2)).sum();
+        assert!((s.dot(&s) - frobenius).abs() < 1e-9);
+        assert!((svd.condition_number() - s.x / s.w).abs() < 1e-12);
+        assert_eq!(svd.rank(svd.default_tolerance()), 4);
+        assert!(svd.pseudo_inverse(svd.default_tolerance()).precision_eq(&m4.inverse().unwrap(), 1e-10));
+
+        let svd = Matrix3x3::new([[2f64, 0., 0.], [0., -3., 0.], [0., 0., 1.]]).svd();
+        assert!(svd.singular_values().precision_eq(&Vector3D::new(3., 2., 1.), 1e-15));
+        assert_eq!(Matrix3x3::<f64>::identity().svd().condition_number(), 1.);
+    }
+
+    #[test]
+    fn svd_rank_deficient() {
+        let m = Matrix3x3::new([[1f64, 2., 3.], [2., 4., 6.], [1., 0., 1.]]);
+        let svd = m.svd();
+        let tol = svd.default_tolerance();
+        assert_eq!(svd.rank(tol), 2);
+        assert!(svd.condition_number() > 1e15);
+        assert!((svd.u().transposed() * svd.u()).precision_eq(&Matrix3x3::identity(), 1e-12));
+        assert!((svd.u() * svd.sigma() * svd.v_t()).precision_eq(&m, 1e-12));
+
+        // Moore-Penrose conditions
+        let pinv = svd.pseudo_inverse(tol);
+        assert!((m.clone() * pinv.clone() * m.clone()).precision_eq(&m, 1e-12));
+        assert!((pinv.clone() * m.clone() * pinv.clone()).precision_eq(&pinv, 1e-12));
+
+        let zero = Matrix3x3::<f64>::zero().svd();
+        assert_eq!(zero.rank(zero.default_tolerance()), 0);
+        assert!((zero.u().transposed() * zero.u()).precision_eq(&Matrix3x3::identity(), 1e-15));
+        assert!(zero.condition_number().is_infinite());
+    }
+
+    #[test]
+    fn svd_nearest_orthogonal() {
+        let r = Quaternion::from_axis_angle(&Vector3D::new(1f64, 2., 3.), 0.7).to_matrix3x3();
+        let drifted = r.clone() + Matrix3x3::new([[1e-3, -2e-3, 0.], [0., 1e-3, 3e-3], [-1e-3, 0., 2e-3]]);
+
+        let fixed = drifted.svd().nearest_orthogonal();
+        assert!((fixed.transposed() * fixed.clone()).precision_eq(&Matrix3x3::identity(), 1e-12));
+        assert!(fixed.precision_eq(&r, 1e-2));
+        assert!(r.svd().nearest_rotation().precision_eq(&r, 1e-12));
+
+        let reflection = Matrix3x3::new([[1f64, 0., 0.], [0., 1., 0.], [0., 0., -1.]]);
+        assert!((reflection.svd().nearest_orthogonal().determinant() + 1.).abs() < 1e-12);
+        assert!((reflection.svd().nearest_rotation().determinant() - 1.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cholesky_decomposition() {
+        let m = Matrix3x3::new([[4f64, 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+        let chol = m.cholesky().unwrap();
+        let l = chol.l();
+        assert_eq!(l, Matrix3x3::new([[2., 0., 0.], [6., 1., 0.], [-8., 5., 3.]]));
+        assert!((l.clone() * l.transposed()).precision_eq(&m, 1e-12));
+        assert!((chol.determinant() - m.determinant()).abs() < 1e-9);
+
+        let x = chol.solve(&Vector3D::new(0., 6., 39.)).unwrap();
+        assert!(x.precision_eq(&Vector3D::new(1., 1., 1.), 1e-12));
+
+        let inv = chol.inverse();
+        assert_eq!(inv.transposed(), inv);
+        assert!((m.clone() * inv).precision_eq(&Matrix3x3::identity(), 1e-9));
+    }
+
+    #[test]
+    fn ldlt_decomposition() {
+        let m = Matrix3x3::new([[4f64, 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+        let ldlt = m.ldlt().unwrap();
+        assert_eq!(ldlt.l(), Matrix3x3::new([[1., 0., 0.], [3., 1., 0.], [-4., 5., 1.]]));
+        assert_eq!(ldlt.d().unwrap(), Vector3D::new(4., 1., 9.));
+        assert!((ldlt.determinant() - 36.).abs() < 1e-9);
+
+        let x = ldlt.solve(&Vector3D::new(0., 6., 39.)).unwrap();
+        assert!(x.precision_eq(&Vector3D::new(1., 1., 1.), 1e-12));
+        let inv = ldlt.inverse();
+        assert_eq!(inv.transposed(), inv);
+        assert!((m * inv).precision_eq(&Matrix3x3::identity(), 1e-9));
+
+        let cov = Matrix4x4::new([
+            [2f32, -1., 0., 0.],
+            [-1., 2., -1., 0.],
+            [0., -1., 2., -1.],
+            [0., 0., -1., 2.]
+        ]);
+        let inv = cov.ldlt().unwrap().inverse();
+        assert!(inv.precision_eq(&cov.cholesky().unwrap().inverse(), 1e-5));
+        assert!((cov * inv).precision_eq(&Matrix4x4::identity(), 1e-5));
+
+        // integers would truncate 1 / 2 to zero and get det 4, rationals keep it exact
+        let int = Matrix2x2::new([[2i64, 1], [1, 2]]);
+        assert!(matches!(int.ldlt(), Err(MatrixErr::NotAField)));
+        let exact = Matrix2x2::<Rational<i64>>::from(int).ldlt().unwrap();
+        assert_eq!(exact.determinant(), Rational::from(3));
+        assert_eq!(exact.solve(&Vector2D::new(Rational::from(1), Rational::from(0))).unwrap(), Vector2D::new(Rational::new(2, 3), Rational::new(-1, 3)));
+    }
+
+    #[test]
+    fn cholesky_not_positive_definite() {
+        let indefinite = Matrix2x2::new([[1f64, 2.], [2., 1.]]);
+        assert!(matches!(indefinite.cholesky(), Err(MatrixErr::NotPositiveDefinite)));
+        assert!(matches!(indefinite.ldlt(), Err(MatrixErr::NotPositiveDefinite)));
+
+        let semidefinite = Matrix2x2::new([[1f64, 1.], [1., 1.]]);
+        assert!(semidefinite.cholesky().is_err());
+        assert!(semidefinite.ldlt().is_err());
+        assert!(Matrix2x2::new([[f64::NAN, 0.], [0., 1.]]).cholesky().is_err());
+    }
+
+    #[test]
+    fn mat_try_index() {
+        let mut m3 = Matrix3x3::<i32>::identity();
+        assert_eq!(m3.try_get_val(2, 2).unwrap(), 1);
+        assert!(matches!(
+            m3.try_get_val(0, 3),
+            Err(MatrixErr::IndexOutOfRange { index: (0, 3), dimensions: (3, 3) })
+        ));
+        assert!(m3.try_set_val(1, 2, 7).is_ok());
+        assert_eq!(m3[(1, 2)], 7);
+        assert!(m3.try_set_val(3, 0, 7).is_err());
+
+        assert!(matches!(m3.try_minor(3, 0), Err(MatrixErr::IndexOutOfRange { .. })));
+        assert_eq!(m3.try_minor(0, 0).unwrap(), m3.minor(0, 0));
+        assert!(m3.try_minor_matrix(0, 5).is_err());
+        assert!(Matrix4x4::<i32>::identity().try_minor_matrix(4, 0).is_err());
+
+        let mn = MatrixMN::new([[1, 2, 3]]);
+        assert!(mn.try_get_val(1, 0).is_err());
+        let dm = DMatrix::from(mn);
+        assert_eq!(dm.try_get_val(0, 2).unwrap(), 3);
+        assert!(dm.try_get_val(0, 3).is_err());
+    }
+
+    #[test]
+    fn mat_try_overflow() {
+        let big = Matrix2x2::new([[100i8, 100], [100, 100]]);
+        assert!(matches!(big.try_add(&big), Err(MatrixErr::Overflow)));
+        let neg = Matrix2x2::new([[-100i8, -100], [-100, -100]]);
+        assert!(matches!(big.try_sub(&neg), Err(MatrixErr::Overflow)));
+        assert!(matches!(big.try_scale(2), Err(MatrixErr::Overflow)));
+        assert!(matches!(big.try_product::<_, Matrix2x2<i8>>(&big), Err(MatrixErr::Overflow)));
+        assert!(matches!(big.try_mul_vec(&Vector2D::new(1, 1)), Err(MatrixErr::Overflow)));
+        assert_eq!(big.try_sub(&big).unwrap(), Matrix2x2::zero());
+
+        let small = Matrix2x2::new([[1i8, 2], [3, 4]]);
+        assert_eq!(small.try_add(&small).unwrap(), small.clone() + small.clone());
+        assert_eq!(small.try_product::<_, Matrix2x2<i8>>(&small).unwrap(), small.clone() * small.clone());
+        assert_eq!(small.try_mul_vec(&Vector2D::new(1, 1)).unwrap(), Vector2D::new(3, 7));
+
+        let huge = Matrix3x3::new([[i64::MAX, 1, 0], [1, i64::MAX, 0], [0, 0, 1]]);
+        assert!(matches!(huge.try_determinant(), Err(MatrixErr::Overflow)));
+        assert!(huge.inverse().is_none());
+        let huge = Matrix2x2::new([[i128::MAX, 1], [1, i128::MAX]]);
+        assert!(matches!(huge.try_inverse(), Err(MatrixErr::Overflow)));
+        assert!(DMatrix::from(huge).determinant().is_err());
+    }
+
+    #[test]
+    fn mat_try_inverse() {
+        let m = Matrix3x3::new([[2, 1, 1], [1, 1, 0], [1, 0, 1]]);
+        assert!(matches!(m.try_inverse(), Err(MatrixErr::Singular)));
+        let unimodular = Matrix2x2::new([[2, 1], [1, 1]]);
+        assert_eq!(unimodular.try_inverse().unwrap(), Matrix2x2::new([[1, -1], [-1, 2]]));
+        let unimodular = Matrix3x3::new([[1, 2, 0], [0, 1, 0], [0, 0, 1]]);
+        assert_eq!(unimodular.try_inverse().unwrap(), Matrix3x3::new([[1, -2, 0], [0, 1, 0], [0, 0, 1]]));
+
+        // the old f32 cut-off threw these away
+        let tiny = Matrix2x2::new([[1e-10f64, 0.], [0., 1e-10]]);
+        assert!(tiny.try_inverse().unwrap().precision_eq(&Matrix2x2::new([[1e10, 0.], [0., 1e10]]), 1e-3));
+        let tiny = Matrix3x3::<f64>::identity() * 1e-10;
+        assert!(tiny.inverse().is_some());
+
+        let nan = Matrix3x3::new([[f64::NAN, 0., 0.], [0., 1., 0.], [0., 0., 1.]]);
+        assert!(matches!(nan.try_inverse(), Err(MatrixErr::NonFinite)));
+        assert!(matches!(nan.try_determinant(), Err(MatrixErr::NonFinite)));
+        assert!(matches!(nan.try_add(&nan), Err(MatrixErr::NonFinite)));
+    }
+
+    #[test]
+    fn rational_arithmetic() {
+        let half = Rational::new(1i64, 2);
+        let third = Rational::new(-2i64, -6);
+        assert_eq!(third, Rational::new(1, 3));
+        assert_eq!(Rational::new(3i64, -6), -half);
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+        assert_eq!(Rational::new(7i64, 2) % Rational::new(1, 1), half);
+        assert!(third < half && -half < third);
+        assert!((Rational::new(2i64, 3) * Rational::new(3, 2)).is_integer());
+        assert_eq!(Rational::new(-6i64, 4).to_string(), "-3/2");
+        assert_eq!(Rational::from(4i64).to_string(), "4");
+        assert_eq!(Rational::<i64>::from_str_radix("10/4", 10).unwrap(), Rational::new(5, 2));
+        assert!(Rational::<i64>::from_str_radix("1/0", 10).is_err());
+        assert_eq!(Rational::new(1i64, 4).to_f64(), Some(0.25));
+        assert!(Rational::try_new(1i64, 0).is_none());
+        assert!(Rational::new(i8::MAX, 1).checked_add(Rational::new(1, 1)).is_none());
+    }
+
+    #[test]
+    fn rational_extremes() {
+        // the cross products don't fit in i32
+        let a = Rational::new(i32::MAX - 1, i32::MAX);
+        let b = Rational::new(i32::MAX - 2, i32::MAX - 1);
+        assert!(b < a && -a < -b);
+        assert_eq!(a.cmp(&a), std::cmp::Ordering::Equal);
+        assert!(Rational::new(i32::MAX, 2) > Rational::new(i32::MAX - 2, 2));
+        assert!(Rational::new(-i32::MAX, 3) < Rational::new(-i32::MAX + 3, 3));
+        // i32::MIN has no negation so it can't be stored
+        assert!(Rational::try_new(i32::MIN, 1).is_none());
+        assert!(Rational::try_new(1, i32::MIN).is_none());
+        assert!(Rational::new(-(1i32 << 30), 1).checked_mul(Rational::from(2)).is_none());
+        assert!(matches!(
+            Rational::<i32>::from_str_radix("-2147483648", 10),
+            Err(ParseRationalErr::OutOfRange)
+        ));
+    }
+
+    #[test]
+    fn rational_parse_errors() {
+        fn parse(s: &str) -> Result<Rational<i64>, Box<dyn std::error::Error>> {
+            Ok(Rational::from_str_radix(s, 10)?)
+        }
+        assert_eq!(parse("3/6").unwrap(), Rational::new(1, 2));
+        assert_eq!(parse("1/0").unwrap_err().to_string(), "Denominator is zero");
+        let err = parse("x/2").unwrap_err();
+        assert_eq!(err.to_string(), "x".parse::<i64>().unwrap_err().to_string());
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn rational_matrices() {
+        let m = Matrix3x3::new([[2i64, 0, 1], [1, 3, 2], [1, 1, 2]]);
+        // the inverse needs fractions, which integers can't hold
+        assert!(matches!(m.try_inverse(), Err(MatrixErr::NotExact)));
+        let exact: Matrix3x3<Rational<i64>> = m.clone().into();
+        let inv = exact.inverse().unwrap();
+        assert_eq!(inv.clone() * exact.clone(), Matrix3x3::identity());
+        assert_eq!(inv[(0, 0)], Rational::new(2, 3));
+        assert_eq!(inv[(0, 1)], Rational::new(1, 6));
+        assert_eq!(inv[(2, 2)], Rational::from(1));
+        assert_eq!(exact.determinant(), Rational::from(m.determinant()));
+
+        let x = exact.solve(&Vector3D::new(1i64, 0, 0).into()).unwrap();
+        assert_eq!(x, Vector3D::new(Rational::new(2, 3), Rational::from(0), Rational::new(-1, 3)));
+
+        let m2 = Matrix2x2::new([[1i64, 2], [3, 4]]);
+        let inv2 = Matrix2x2::<Rational<i64>>::from(m2).inverse().unwrap();
+        assert_eq!(inv2, Matrix2x2::new([[Rational::from(-2), Rational::from(1)], [Rational::new(3, 2), Rational::new(-1, 2)]]));
+
+        let mn: MatrixMN<Rational<i64>, 3, 3> = MatrixMN::new([[1i64, 2, 3], [0, 1, 4], [5, 6, 0]]).into();
+        let inv = mn.inverse().unwrap();
+        assert_eq!(mn.product(&inv), MatrixMN::identity());
+        assert_eq!(inv[(0, 0)], Rational::from(-24));
+        let singular: Matrix2x2<Rational<i64>> = Matrix2x2::new([[1i64, 2], [2, 4]]).into();
+        assert!(matches!(singular.try_inverse(), Err(MatrixErr::Singular)));
+    }
+
+    #[test]
+    fn complex_arithmetic() {
+        let z = Complex::new(3.0f64, 4.0);
+        let w = Complex::new(1.0f64, -2.0);
+        assert_eq!(z + w, Complex::new(4., 2.));
+        assert_eq!(z - w, Complex::new(2., 6.));
+        assert_eq!(z * w, Complex::new(11., -2.));
+        assert_eq!(z / w, Complex::new(-1., 2.));
+        assert_eq!(Complex::<f64>::i() * Complex::i(), -Complex::from(1.));
+        assert_eq!(z.norm(), 5.);
+        assert_eq!(z.norm_sqr(), 25.);
+        assert_eq!(z.conj(), Complex::new(3., -4.));
+        assert_eq!(z * z.conj(), Complex::from(25.));
+        assert!((Complex::from_polar(2.0f64, std::f64::consts::FRAC_PI_2) - Complex::new(0., 2.)).norm() < 1e-12);
+        assert!(f64_eq(Complex::new(0.0f64, 1.0).arg(), std::f64::consts::FRAC_PI_2));
+        assert_eq!(z.to_string(), "3+4i");
+        assert_eq!(w.to_string(), "1-2i");
+        assert_eq!(Complex::<f64>::from_str_radix("3+4i", 10).unwrap(), z);
+        assert_eq!(Complex::<f64>::from_str_radix("-2.5e-1-i", 10).unwrap(), Complex::new(-0.25, -1.));
+        assert_eq!(Complex::<f64>::from_str_radix("2i", 10).unwrap(), Complex::new(0., 2.));
+        assert_eq!(Complex::<f64>::from_str_radix("-i", 10).unwrap(), Complex::new(0., -1.));
+        assert_eq!(Complex::<f64>::from_str_radix("7", 10).unwrap(), Complex::from(7.));
+        assert!(Complex::<f64>::from_str_radix("3+4j", 10).is_err());
+        assert_eq!(Complex::new(2.0f64, 0.).to_f64(), Some(2.));
+        assert_eq!(z.to_f64(), None);
+        assert!(!Complex::new(f64::NAN, 0.).is_finite());
+    }
+
+    #[test]
+    fn complex_matrices() {
+        let i = Complex::<f64>::i();
+        let c = |re: f64, im: f64| Complex::new(re, im);
+        let zero = Complex::from(0.);
+
+        let pauli_y = Matrix2x2::new([[zero, -i], [i, zero]]);
+        assert!(pauli_y.is_hermitian(0.));
+        assert!(pauli_y.is_unitary(1e-12));
+        assert_eq!(pauli_y.clone() * pauli_y.clone(), Matrix2x2::identity());
+
+        let h = Matrix2x2::new([[c(2., 0.), c(1., -1.)], [c(1., 1.), c(3., 0.)]]);
+        assert!(h.is_hermitian(0.));
+        assert!(!h.is_unitary(1e-12));
+        assert_eq!(h.determinant(), c(4., 0.));
+        let inv = h.inverse().unwrap();
+        assert!(inv.precision_eq(&Matrix2x2::new([[c(0.75, 0.), c(-0.25, 0.25)], [c(-0.25, -0.25), c(0.5, 0.)]]), 1e-12));
+        assert!((h.clone() * inv).precision_eq(&Matrix2x2::identity(), 1e-12));
+
+        // the adjoint also conjugates, the transpose doesn't
+        let m = Matrix2x3::new([[c(1., 1.), c(2., 0.), c(0., -3.)], [zero, c(4., 2.), c(5., 0.)]]);
+        let adj = m.adjoint();
+        assert_eq!(adj.get_val(0, 0), c(1., -1.));
+        assert_eq!(adj.get_val(2, 0), c(0., 3.));
+        assert_eq!(m.transposed().get_val(2, 0), c(0., -3.));
+        assert!(!Matrix2x2::new([[c(1., 0.), i], [i, c(1., 0.)]]).is_hermitian(1e-12));
+
+        let a = Matrix3x3::new([
+            [c(1., 0.), i, zero],
+            [-i, c(2., 0.), c(1., 0.)],
+            [zero, c(1., 0.), c(3., 0.)],
+        ]);
+        assert!(a.is_hermitian(0.));
+        let x = Vector3D::new(c(1., 0.), c(1., 1.), -i);
+        let b = a.mul_vec(&x);
+        assert!(a.solve(&b).unwrap().precision_eq(&x, 1e-12));
+        assert!(a.lu().unwrap().reciprocal_condition() > 0.01);
+
+        // real matrices are their own conjugates
+        let rotation = Matrix2x2::new([[0.6, -0.8], [0.8, 0.6]]);
+        assert!(rotation.is_unitary(1e-12));
+        assert_eq!(rotation.adjoint(), rotation.transposed());
+        assert!(Matrix3x3::new([[1, 2, 3], [2, 4, 5], [3, 5, 6]]).is_hermitian(0));
+
+        let d = DMatrix::from_func(2, 3, |r, k| c(r as f64, k as f64));
+        assert_eq!(d.adjoint().get_val(2, 1), c(1., -2.));
+    }
+
+    #[test]
+    fn unsigned_scalars() {
+        let p = Vector2D::new(640u32, 480);
+        assert_eq!(p.add(&Vector2D::new(10, 20)), Vector2D::new(650, 500));
+        assert_eq!(p.sub(&Vector2D::new(40, 80)), Vector2D::new(600, 400));
+        assert_eq!(p.dot(&Vector2D::new(1, 2)), 1600);
+        assert_eq!(p.scale(2), Vector2D::new(1280, 960));
+        assert_eq!(p.magnitude_f64(), 800.);
+        // the difference is taken the right way round instead of wrapping
+        assert!(Vector2D::new(1u32, 5).precision_eq(&Vector2D::new(3, 5), 2));
+        assert!(!Vector2D::new(1u32, 5).precision_eq(&Vector2D::new(3, 5), 1));
+
+        let cell = Vector3D::new(2usize, 3, 4);
+        assert_eq!(&cell + &Vector3D::new(1, 1, 1), Vector3D::new(3, 4, 5));
+        assert_eq!(cell.dot(&Vector3D::new(1, 10, 100)), 432);
+        assert_eq!(VectorN::new([1u8, 2, 3]).scale(3), VectorN::new([3, 6, 9]));
+        assert_eq!(7u8.distance(9), 2);
+        assert_eq!(DVector::new(vec![1u64, 2]).dot(&DVector::new(vec![3, 4])).unwrap(), 11);
+
+        let m = Matrix2x2::new([[3u64, 1], [1, 2]]);
+        assert_eq!(m.determinant(), 5);
+        assert_eq!(m.mul_vec(&Vector2D::new(1, 1)), Vector2D::new(4, 3));
+        assert!(m.precision_eq(&Matrix2x2::new([[2, 1], [1, 3]]), 1));
+        // a negative determinant has no unsigned answer
+        let swapped = Matrix2x2::new([[1u64, 2], [3, 1]]);
+        assert!(matches!(swapped.try_determinant(), Err(MatrixErr::Overflow)));
+        assert_eq!(Matrix3x3::new([[0u32, 1, 0], [1, 0, 0], [0, 0, 1]]).try_determinant().ok(), None);
+        // two swaps make +1, even though the cofactors on the way are -1
+        let swaps = Matrix4x4::new([[0u32, 1, 0, 0], [1, 0, 0, 0], [0, 0, 0, 1], [0, 0, 1, 0]]);
+        assert_eq!(swaps.determinant(), 1);
+        assert_eq!(swaps.try_determinant().unwrap(), 1);
+
+        // signed types still get the rest
+        fn negated<T: SignedScalar>(v: Vector2D<T>) -> Vector2D<T> {
+            v.invert()
+        }
+        assert_eq!(negated(Vector2D::new(1i32, -2)), Vector2D::new(-1, 2));
+    }
+
+    #[test]
+    fn fixed_arithmetic() {
+        let q = Q16_16::from_f64;
+        assert_eq!(Q16_16::ONE.raw(), 1 << 16);
+        assert_eq!(q(1.5) + q(2.25), q(3.75));
+        assert_eq!(q(1.5) * q(-2.5), q(-3.75));
+        assert_eq!(q(1.) / q(3.), Q16_16::from_raw(21845));
+        assert_eq!(Q16_16::from_ratio(1, 3), q(1.) / q(3.));
+        // 1/3 * 3 rounds back to just under one, the same way everywhere
+        assert_eq!((q(1.) / q(3.)) * q(3.), Q16_16::from_raw(65535));
+        assert_eq!(q(30000.) * q(30000.), Q16_16::MAX);
+        assert_eq!(q(-30000.) * q(30000.), Q16_16::MIN);
+        assert_eq!(Q16_16::MAX + Q16_16::ONE, Q16_16::MAX);
+        assert_eq!(q(5.) / Q16_16::ZERO, Q16_16::MAX);
+        assert_eq!(-Q16_16::MIN, Q16_16::MAX);
+        assert_eq!(q(30000.).checked_mul(q(30000.)), None);
+        assert_eq!(q(1.).checked_div(Q16_16::ZERO), None);
+        assert_eq!(q(7.5) % q(2.), q(1.5));
+
+        assert_eq!(q(2.).sqrt().raw(), 92681);
+        assert_eq!(q(6.25).sqrt(), q(2.5));
+        assert_eq!(q(-1.).sqrt(), Q16_16::ZERO);
+        assert_eq!(Q32_32::from_int(2).sqrt().raw(), 6074000999);
+        assert_eq!(Vector3D::new(q(2.), q(3.), q(6.)).magnitude(), q(7.));
+        assert_eq!(Vector2D::new(3.0f64, 4.).magnitude(), 5.);
+
+        assert_eq!(q(-12.375).to_string(), "-12.375");
+        assert_eq!(Q16_16::EPSILON.to_string(), "0.0000152587890625");
+        assert_eq!(Q32_32::from_int(-3).to_string(), "-3");
+        assert_eq!(Q16_16::from_str_radix("-12.375", 10).unwrap(), q(-12.375));
+        assert_eq!(Q16_16::from_str_radix("0.1", 10).unwrap().raw(), 6554);
+        assert_eq!(Q32_32::from_str_radix(".5", 10).unwrap(), Q32_32::from_f64(0.5));
+        assert!(matches!(Q16_16::from_str_radix("40000", 10), Err(ParseFixedErr::OutOfRange)));
+        assert!(matches!(Q16_16::from_str_radix("1e3", 10), Err(ParseFixedErr::Invalid)));
+        assert!(matches!(Q16_16::from_str_radix("1.x", 10), Err(ParseFixedErr::Invalid)));
+        let err: Box<dyn std::error::Error> = Q16_16::from_str_radix("1e3", 10).unwrap_err().into();
+        assert_eq!(err.to_string(), "Invalid fixed-point number");
+        assert_eq!(q(-2.75).to_i64(), Some(-2));
+        assert_eq!(q(-2.75).to_f64(), Some(-2.75));
+    }
+
+    #[test]
+    fn fixed_bit_identical() {
+        let v = |r: [i32; 3]| Vector3D::new(Q16_16::from_raw(r[0]), Q16_16::from_raw(r[1]), Q16_16::from_raw(r[2]));
+        let c = v([51404, 164335, 176140]).cross(&v([-28906, 109526, -90026]));
+        assert_eq!([c.x.raw(), c.y.raw(), c.z.raw()], [-520116, -7077, 158391]);
+
+        let v = |r: [i64; 3]| Vector3D::new(Q32_32::from_raw(r[0]), Q32_32::from_raw(r[1]), Q32_32::from_raw(r[2]));
+        let c = v([-11967424738, -4723546254, -19343448243]).cross(&v([-18381426761, 430507637, 2185825083]));
+        assert_eq!([c.x.raw(), c.y.raw(), c.z.raw()], [-465042816, 88875851211, -21415200844]);
+
+        let a = [
+            [37622, -193540, 18096, 148219],
+            [176161, -60894, -71902, 136312],
+            [-79991, -191298, -41106, -38251],
+            [-20939, 152942, -122186, 193155],
+        ];
+        let b = [
+            [119069, -34022, -184979, -81081],
+            [119428, -63698, -185927, -115889],
+            [121135, 153311, 134194, -182259],
+            [47043, 42880, 117276, 131988],
+        ];
+        let expected = [
+            [-144498, 307893, 745177, 543880],
+            [174034, -111279, -227766, 364226],
+            [-597375, 106270, 615875, 474523],
+            [153472, -297237, -279343, 484271],
+        ];
+        let m = |r: [[i32; 4]; 4]| Matrix4x4::from_func(|i, j| Q16_16::from_raw(r[i][j]));
+        let product = m(a).cross(&m(b));
+        assert_eq!(Matrix4x4::from_func(|i, j| product.get_val(i, j).raw()), Matrix4x4::new(expected));
+
+        let a = [
+            [-9263931218, 10835340531, 3787302741, 5888231250],
+            [4298171749, 4837890692, 40419241, -4969955945],
+            [-701894160, 866167133, -3944924174, -12366284299],
+            [8494353007, 9070650502, 685229524, 86327088],
+        ];
+        let b = [
+            [4459321025, -7073672061, 701232118, 9919977268],
+            [3989209462, 5014297910, -1123746300, 6736750897],
+            [-10412310894, -9577156907, -1773418247, -3310150724],
+            [-11469803769, 9637963396, -54710418, 10422820163],
+        ];
+        let expected = [
+            [-24460682380, 32675592568, -5986299206, 6969188208],
+            [22130517452, -12673574294, -517423623, 5423715266],
+            [42663882942, -16786262501, 1445184987, -27232078282],
+            [15352571888, -4734345023, -1270444673, 33528092398],
+        ];
+        let m = |r: [[i64; 4]; 4]| Matrix4x4::from_func(|i, j| Q32_32::from_raw(r[i][j]));
+        let product = m(a).cross(&m(b));
+        assert_eq!(Matrix4x4::from_func(|i, j| product.get_val(i, j).raw()), Matrix4x4::new(expected));
+    }
+
+    #[test]
+    fn fixed_matrices() {
+        let q = Q16_16::from_f64;
+        let m = Matrix2x2::new([[q(2.), q(0.)], [q(0.), q(4.)]]);
+        assert_eq!(m.determinant(), q(8.));
+        assert_eq!(m.inverse().unwrap(), Matrix2x2::new([[q(0.5), q(0.)], [q(0.), q(0.25)]]));
+        assert_eq!(m.solve(&Vector2D::new(q(1.), q(1.))).unwrap(), Vector2D::new(q(0.5), q(0.25)));
+        assert!(matches!(m.try_scale(q(20000.)), Err(MatrixErr::Overflow)));
+        assert!(m.precision_eq(&Matrix2x2::new([[q(2.), Q16_16::EPSILON], [q(0.), q(4.)]]), Q16_16::EPSILON));
+    }
+
+    #[test]
+    fn dual_arithmetic() {
+        let x = Dual::variable(2.0f64);
+        let f = x * x * x + x.sin();
+        assert_eq!(f.re, 8. + 2f64.sin());
+        assert!(f64_eq(f.eps, 12. + 2f64.cos()));
+
+        let g = (x + Dual::constant(1.)) / (x - Dual::constant(1.));
+        assert_eq!(g, Dual::new(3., -2.));
+        assert_eq!(x.recip(), Dual::new(0.5, -0.25));
+        assert_eq!(x.powi(3), Dual::new(8., 12.));
+        assert_eq!(Dual::variable(9.0f64).sqrt(), Dual::new(3., 1. / 6.));
+        assert!(f64_eq(x.exp().ln().eps, 1.));
+        assert!(f64_eq(x.powf(x).eps, 4. * (1. + 2f64.ln())));
+        // d/dt atan2(sin t, cos t) = 1
+        let t = Dual::variable(0.7f64);
+        assert!(f64_eq(t.sin().atan2(t.cos()).eps, 1.));
+        assert_eq!((-x).abs(), Dual::new(2., 1.));
+        assert_eq!(x.floor(), Dual::constant(2.));
+
+        assert!(x > Dual::constant(1.) && x < Dual::constant(3.));
+        assert_eq!(x.to_string(), "2+1ε");
+        assert_eq!(Dual::<f64>::from_str_radix("1.5", 10).unwrap(), Dual::constant(1.5));
+        assert_eq!(x.to_f64(), Some(2.));
+        assert!(!Scalar::is_finite(Dual::new(1., f64::NAN)));
+        assert!(Dual::new(1., f64::INFINITY).is_infinite());
+    }
+
+    #[test]
+    fn dual_constant_at_zero() {
+        // constants have no derivative even where f' is infinite
+        let zero = Dual::constant(0.0f64);
+        assert_eq!(zero.sqrt(), Dual::constant(0.));
+        assert_eq!(zero.powf(Dual::constant(0.5)), Dual::constant(0.));
+        assert_eq!(zero.cbrt(), Dual::constant(0.));
+        assert_eq!(zero.hypot(zero), Dual::constant(0.));
+        assert_eq!(zero.atan2(zero), Dual::constant(0.));
+        assert_eq!(zero.atan2(Dual::constant(-1.)), Dual::constant(std::f64::consts::PI));
+        assert_eq!(Vector3D::new(zero, zero, zero).magnitude(), Dual::constant(0.));
+        // a variable at zero still gets the infinite slope
+        assert_eq!(Dual::variable(0.0f64).sqrt().eps, f64::INFINITY);
+    }
+
+    #[test]
+    fn dual_gradient_jacobian() {
+        let sq_len = |v: VectorN<Dual<f64>, 3>| v.dot(&v);
+        assert_eq!(gradient(sq_len, &VectorN::new([1., 2., 3.])), VectorN::new([2., 4., 6.]));
+
+        let polar = |v: VectorN<Dual<f64>, 2>| VectorN::new([v.data[0] * v.data[1].cos(), v.data[0] * v.data[1].sin()]);
+        let (r, theta) = (2.0f64, 0.5f64);
+        let j = jacobian(polar, &VectorN::new([r, theta]));
+        assert!(j.precision_eq(&MatrixMN::new([[theta.cos(), -r * theta.sin()], [theta.sin(), r * theta.cos()]]), 1e-15));
+
+        // a linear map is its own jacobian
+        let a = Matrix3x3::new([[1., 2., 0.], [0., -1., 4.], [3., 0., 5.]]);
+        let linear = |v: VectorN<Dual<f64>, 3>| {
+            let a: Matrix3x3<Dual<f64>> = Matrix3x3::from_func(|i, j| Dual::constant(a.get_val(i, j)));
+            let out = a.mul_vec(&Vector3D::new(v.data[0], v.data[1], v.data[2]));
+            VectorN::new([out.x, out.y, out.z])
+        };
+        let j = jacobian(linear, &VectorN::new([0.3, -1., 2.]));
+        assert_eq!(Matrix3x3::from_func(|i, k| j.get_val(i, k)), a);
+
+        // rotating x around z by the angle, the derivative points along the circle
+        let rotate = |v: VectorN<Dual<f64>, 1>| {
+            let z = Vector3D::new(Dual::constant(0.), Dual::constant(0.), Dual::constant(1.));
+            let p = Quaternion::from_axis_angle(&z, v.data[0]).rotate(&Vector3D::new(Dual::constant(1.), Dual::constant(0.), Dual::constant(0.)));
+            VectorN::new([p.x, p.y, p.z])
+        };
+        let j = jacobian(rotate, &VectorN::new([0.4]));
+        assert!(j.precision_eq(&MatrixMN::new([[-0.4f64.sin()], [0.4f64.cos()], [0.]]), 1e-15));
+    }
+
+    #[test]
+    fn interval_arithmetic() {
+        let iv = Interval::new;
+        assert_eq!(iv(1.0f64, 2.) + iv(3., 4.), iv(4., 6.));
+        assert_eq!(iv(1.0f64, 2.) - iv(3., 4.), iv(-3., -1.));
+        assert_eq!(iv(1.0f64, 2.) * iv(-1., 3.), iv(-2., 6.));
+        assert_eq!(iv(1.0f64, 2.) / iv(4., 8.), iv(0.125, 0.5));
+        assert_eq!(iv(1.0f64, 2.) / iv(-1., 1.), Interval::entire());
+        assert_eq!(iv(-3.0f64, 2.).abs(), iv(0., 3.));
+        assert_eq!(iv(4.0f64, 9.).sqrt(), iv(2., 3.));
+
+        // 0.1 + 0.2 rounds up, so only the lower bound moves
+        let sum = Interval::point(0.1f64) + Interval::point(0.2);
+        assert_eq!(sum.hi(), 0.1 + 0.2);
+        assert!(sum.lo() < sum.hi() && sum.width() < 1e-16);
+        let third = Interval::point(1.0f64) / Interval::point(3.);
+        assert!(third.lo() < third.hi() && (third * Interval::point(3.)).contains(1.));
+        assert!(Interval::point(2.0f64).sqrt().contains(std::f64::consts::SQRT_2));
+
+        // the text 0.1 isn't a float, both neighbours are kept
+        let tenth = Interval::<f64>::from_str_radix("0.1", 10).unwrap();
+        assert!(tenth.lo() < 0.1 && tenth.contains(0.1));
+        assert_eq!(Interval::<f64>::from_str_radix("[1, 2]", 10).unwrap().hi(), 2. + 2. * f64::EPSILON + f64::MIN_POSITIVE);
+        assert!(matches!(Interval::<f64>::from_str_radix("[2, 1]", 10), Err(ParseIntervalErr::Format)));
+        let err: Box<dyn std::error::Error> = Interval::<f64>::from_str_radix("[1 2]", 10).unwrap_err().into();
+        assert_eq!(err.to_string(), "Expected x or [lo, hi] with lo <= hi");
+        let err: Box<dyn std::error::Error> = Interval::<f64>::from_str_radix("[1, x]", 10).unwrap_err().into();
+        assert_eq!(err.to_string(), "x".parse::<f64>().unwrap_err().to_string());
+
+        assert_eq!(iv(1.0f64, 2.).sign(), Some(std::cmp::Ordering::Greater));
+        assert_eq!(iv(-1.0f64, 2.).sign(), None);
+        assert!(iv(1.0f64, 2.) < iv(3., 4.));
+        assert_eq!(iv(1.0f64, 3.).partial_cmp(&iv(2., 4.)), None);
+        assert_eq!(iv(1.0f64, 2.).to_string(), "[1, 2]");
+        assert_eq!(iv(1.0f64, 2.).to_f64(), Some(1.5));
+        assert!(!Interval::<f64>::entire().is_finite());
+    }
+
+    #[test]
+    fn interval_matrices() {
+        let to_interval = |m: [[i64; 3]; 3]| Matrix3x3::from_func(|i, j| Interval::point(m[i][j] as f64));
+
+        // plain f64 rounds the determinant of these nearly collinear points to 0, it is really 5
+        let rows = [[67108938, 268435752, 1], [67109375, 268437501, 1], [67108933, 268435732, 1]];
+        assert_eq!(Matrix3x3::from_func(|i, j| rows[i][j] as f64).determinant(), 0.);
+        assert_eq!(Matrix3x3::new(rows).determinant(), 5);
+        let det = to_interval(rows).determinant();
+        assert!(det.contains(5.));
+        assert_ne!(det.sign(), Some(std::cmp::Ordering::Less));
+
+        // exact products stay points, so the sign is certain
+        let m = to_interval([[2, 0, 1], [1, 3, 2], [1, 1, 2]]);
+        assert_eq!(m.determinant(), Interval::point(6.));
+        assert_eq!(m.determinant().sign(), Some(std::cmp::Ordering::Greater));
+
+        let inv = m.inverse().unwrap();
+        let exact = [[2. / 3., 1. / 6., -0.5], [0., 0.5, -0.5], [-1. / 3., -1. / 3., 1.]];
+        for (i, row) in exact.iter().enumerate() {
+            for (j, v) in row.iter().enumerate() {
+                assert!(inv.get_val(i, j).contains(*v));
+                assert!(inv.get_val(i, j).width() < 1e-15);
+            }
+        }
+        assert_eq!(inv.get_val(1, 1), Interval::point(0.5));
+
+        let singular = to_interval([[1, 2, 3], [2, 4, 6], [1, 1, 1]]);
+        assert!(matches!(singular.try_inverse(), Err(MatrixErr::Singular)));
+
+        // the decimals themselves are enclosed, so the true 0.4 is too
+        let parse = |s| Interval::<f64>::from_str_radix(s, 10).unwrap();
+        let a = Vector3D::new(parse("0.1"), parse("0.2"), parse("0.3"));
+        let dot = a.dot(&Vector3D::new(Interval::point(3.), Interval::point(-1.), Interval::point(1.)));
+        assert!(dot.contains(0.4) && dot.width() < 1e-15);
+    }
 }
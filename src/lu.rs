@@ -0,0 +1,221 @@
+use crate::errors::MatrixErr;
+use crate::matrices::SquareMatrix;
+use crate::types::{Scalar, SignedScalar};
+use crate::util::{max_norm, rounding_tolerance};
+use crate::vectors::Vector;
+use num_traits::{One, Zero};
+use std::marker::PhantomData;
+
+// PA = LU with partial pivoting, L has an implied unit diagonal and is stored below U
+#[derive(Debug, Clone, PartialEq)]
+pub struct LU<T: Scalar, M> {
+    _n: usize,
+    _lu: Vec<T>,
+    _perm: Vec<usize>, // row i of PA is row _perm[i] of A
+    _sign: T,          // determinant of P, flips with every row swap
+    _singular: bool,
+    _norm: T::Real, // largest absolute column sum of A, for the condition estimate
+    _matrix: PhantomData<M>,
+}
+
+impl<T: SignedScalar, M: SquareMatrix<T>> LU<T, M> {
+    // elimination needs exact division, integers would truncate every factor. their determinants
+    // go through Bareiss and their solves through Cramer's rule instead
+    pub fn new(m: &M) -> Result<Self, MatrixErr> {
+        let (n, _) = m.dimensions();
+        let mut lu = Vec::with_capacity(n * n);
+        for i in 0..n {
+            for j in 0..n {
+                lu.push(m.get_val(i, j));
+            }
+        }
+        Self::from_data(lu, n)
+    }
+
+    pub fn l(&self) -> M {
+        M::from_func(|i, j| match i.cmp(&j) {
+            std::cmp::Ordering::Greater => self._lu[i * self._n + j],
+            std::cmp::Ordering::Equal => T::one(),
+            std::cmp::Ordering::Less => T::default(),
+        })
+    }
+
+    pub fn u(&self) -> M {
+        M::from_func(|i, j| {
+            if i <= j {
+                self._lu[i * self._n + j]
+            } else {
+                T::default()
+            }
+        })
+    }
+
+    // the permutation as a matrix, so that p() * a == l() * u()
+    pub fn p(&self) -> M {
+        M::from_func(|i, j| {
+            if self._perm[i] == j {
+                T::one()
+            } else {
+                T::default()
+            }
+        })
+    }
+
+    // solves A x = b, None if A is singular
+    pub fn solve(&self, b: &M::VecOut) -> Option<M::VecIn> {
+        if self._singular {
+            return None;
+        }
+        let x = self.solve_slice(&b.to_vec());
+        M::VecIn::from_slice(&x).ok()
+    }
+
+    pub fn inverse(&self) -> Option<M> {
+        if self._singular {
+            return None;
+        }
+
+        let columns = self.inverse_columns();
+        Some(M::from_func(|i, j| columns[j][i]))
+    }
+}
+
+// everything that works on the factors alone, so DMatrix can use them without being a SquareMatrix
+impl<T: SignedScalar, M> LU<T, M> {
+    // factors a row-major n x n matrix
+    pub(crate) fn from_data(mut lu: Vec<T>, n: usize) -> Result<Self, MatrixErr> {
+        if !T::IS_FIELD {
+            return Err(MatrixErr::NotAField);
+        }
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut sign = T::one();
+        let mut singular = false;
+
+        let tolerance = rounding_tolerance::<T>(max_norm(&lu), n);
+        let norm = column_norm(&lu, n);
+
+        for k in 0..n {
+            let mut pivot = k;
+            for r in k + 1..n {
+                if lu[r * n + k].norm() > lu[pivot * n + k].norm() {
+                    pivot = r;
+                }
+            }
+            if pivot != k {
+                for c in 0..n {
+                    lu.swap(k * n + c, pivot * n + c);
+                }
+                perm.swap(k, pivot);
+                sign = -sign;
+            }
+
+            let p = lu[k * n + k];
+            if p.norm() <= tolerance {
+                singular = true;
+                continue;
+            }
+            for r in k + 1..n {
+                let factor = lu[r * n + k] / p;
+                lu[r * n + k] = factor;
+                for c in k + 1..n {
+                    lu[r * n + c] = lu[r * n + c] - factor * lu[k * n + c];
+                }
+            }
+        }
+
+        Ok(Self {
+            _n: n,
+            _lu: lu,
+            _perm: perm,
+            _sign: sign,
+            _singular: singular,
+            _norm: norm,
+            _matrix: PhantomData,
+        })
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self._singular
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self._perm
+    }
+
+    pub fn sign(&self) -> T {
+        self._sign
+    }
+
+    pub fn determinant(&self) -> T {
+        (0..self._n).fold(self._sign, |acc, k| acc * self._lu[k * self._n + k])
+    }
+
+    // 1 / (|A| * |A^-1|) in the 1-norm, zero for singular matrices and close to one for well behaved ones
+    pub fn reciprocal_condition(&self) -> T::Real {
+        if self._singular {
+            return T::Real::zero();
+        }
+
+        let inv_norm = self
+            .inverse_columns()
+            .iter()
+            .map(|c| c.iter().fold(T::Real::zero(), |acc, v| acc + v.norm()))
+            .fold(T::Real::zero(), |acc, v| if v > acc { v } else { acc });
+        T::Real::one() / (self._norm * inv_norm)
+    }
+
+    // like is_singular but also rejects systems where rounding would swamp every digit of the answer
+    pub(crate) fn check(&self) -> Result<(), MatrixErr> {
+        if self._singular {
+            return Err(MatrixErr::Singular);
+        }
+        // exact types can't lose digits to rounding, so only singularity matters for them
+        if T::machine_epsilon().is_zero() {
+            return Ok(());
+        }
+        if self.reciprocal_condition() <= rounding_tolerance::<T>(T::Real::one(), self._n) {
+            return Err(MatrixErr::IllConditioned);
+        }
+        Ok(())
+    }
+
+    // A^-1 one column at a time
+    pub(crate) fn inverse_columns(&self) -> Vec<Vec<T>> {
+        let n = self._n;
+        (0..n)
+            .map(|j| {
+                let e: Vec<T> = (0..n)
+                    .map(|i| if i == j { T::one() } else { T::default() })
+                    .collect();
+                self.solve_slice(&e)
+            })
+            .collect()
+    }
+
+    pub(crate) fn solve_slice(&self, b: &[T]) -> Vec<T> {
+        let n = self._n;
+        let mut x: Vec<T> = self._perm.iter().map(|&p| b[p]).collect();
+
+        // forward substitution with the unit lower triangle
+        for i in 0..n {
+            for k in 0..i {
+                x[i] = x[i] - self._lu[i * n + k] * x[k];
+            }
+        }
+        // back substitution with the upper triangle
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                x[i] = x[i] - self._lu[i * n + k] * x[k];
+            }
+            x[i] = x[i] / self._lu[i * n + i];
+        }
+        x
+    }
+}
+
+// largest absolute column sum of a row-major n x n matrix
+fn column_norm<T: Scalar>(data: &[T], n: usize) -> T::Real {
+    (0..n)
+        .map(|j| (0..n).fold(T::Real::zero(), |acc, i| acc + data[i * n + j].norm()))
+        .fold(T::Real::zero(), |acc, v| if v > acc { v } else { acc })
+}
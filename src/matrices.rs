@@ -1,193 +1,791 @@
-use crate::types::Scalar;
-use crate::vectors::{Vector, Vector3D, Vector4D};
-
-pub trait Matrix<T: Scalar>: Sized {
-    type VEC: Vector<T>;
-    fn zero() -> Self;
+use crate::cholesky::{Cholesky, LDLT};
+use crate::errors::MatrixErr;
+use crate::lu::LU;
+use crate::qr::QR;
+use crate::svd::SVD;
+use crate::types::{Scalar, SignedScalar};
+use crate::util::{determinant, max_norm};
+use crate::vectors::{Vector, Vector2D, Vector3D, Vector4D, VectorN};
+use num_traits::{Float, One};
+use std::cell::Cell;
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+// operations available for every matrix shape
+pub trait MatrixOps<T: Scalar>: Sized {
+    type VecIn: Vector<T>; // what the matrix multiplies, has as many elements as there are columns
+    type VecOut: Vector<T>; // what comes out, has as many elements as there are rows
+    type Transposed: MatrixOps<T, VecIn = Self::VecOut, VecOut = Self::VecIn>;
     fn dimensions(&self) -> (usize, usize); // EXCLUSIVE
-    fn identity() -> Self;
     fn from_func(f: impl Fn(usize, usize) -> T) -> Self;
     fn get_val(&self, i: usize, j: usize) -> T;
+    fn mul_vec(&self, v: &Self::VecIn) -> Self::VecOut;
+
+    fn zero() -> Self {
+        Self::from_func(|_, _| T::default())
+    }
+
+    fn transposed(&self) -> Self::Transposed {
+        Self::Transposed::from_func(|i, j| self.get_val(j, i))
+    }
+
+    // conjugate transpose, the same as transposed for real matrices
+    fn adjoint(&self) -> Self::Transposed {
+        Self::Transposed::from_func(|i, j| self.get_val(j, i).conj())
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self::from_func(|i, j| self.get_val(i, j) + other.get_val(i, j))
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        Self::from_func(|i, j| self.get_val(i, j) - other.get_val(i, j))
+    }
+
+    // the vector types stand in for the dimensions, so mismatched shapes don't compile
+    fn product<M, O>(&self, other: &M) -> O
+    where
+        M: MatrixOps<T, VecOut = Self::VecIn>,
+        O: MatrixOps<T, VecIn = M::VecIn, VecOut = Self::VecOut>,
+    {
+        let (_, n) = self.dimensions();
+        O::from_func(|i, j| {
+            let mut val = T::default();
+            for k in 0..n {
+                val = val + self.get_val(i, k) * other.get_val(k, j)
+            }
+            val
+        })
+    }
+
+    fn precision_eq(&self, other: &Self, precision: T::Real) -> bool {
+        let (rows, cols) = self.dimensions();
+        for i in 0..rows {
+            for j in 0..cols {
+                if self.get_val(i, j).distance(other.get_val(i, j)) > precision {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    // from_func for element functions that can fail, the first error is returned
+    fn try_from_func(f: impl Fn(usize, usize) -> Result<T, MatrixErr>) -> Result<Self, MatrixErr> {
+        let err = Cell::new(None);
+        let m = Self::from_func(|i, j| {
+            f(i, j).unwrap_or_else(|e| {
+                let first = err.take();
+                err.set(first.or(Some(e)));
+                T::default()
+            })
+        });
+        match err.into_inner() {
+            Some(e) => Err(e),
+            None => Ok(m),
+        }
+    }
+
+    fn try_get_val(&self, i: usize, j: usize) -> Result<T, MatrixErr> {
+        let (rows, cols) = self.dimensions();
+        if i >= rows || j >= cols {
+            return Err(MatrixErr::IndexOutOfRange {
+                index: (i, j),
+                dimensions: (rows, cols),
+            });
+        }
+        Ok(self.get_val(i, j))
+    }
+
+    // the try_ versions report overflow and NaN / infinity as errors instead of panicking or wrapping
+    fn try_add(&self, other: &Self) -> Result<Self, MatrixErr> {
+        Self::try_from_func(|i, j| checked(self.get_val(i, j).checked_add(other.get_val(i, j))))
+    }
+
+    fn try_sub(&self, other: &Self) -> Result<Self, MatrixErr> {
+        Self::try_from_func(|i, j| checked(self.get_val(i, j).checked_sub(other.get_val(i, j))))
+    }
+
+    fn try_scale(&self, a: T) -> Result<Self, MatrixErr> {
+        Self::try_from_func(|i, j| checked(self.get_val(i, j).checked_mul(a)))
+    }
+
+    fn try_product<M, O>(&self, other: &M) -> Result<O, MatrixErr>
+    where
+        M: MatrixOps<T, VecOut = Self::VecIn>,
+        O: MatrixOps<T, VecIn = M::VecIn, VecOut = Self::VecOut>,
+    {
+        let (_, n) = self.dimensions();
+        O::try_from_func(|i, j| {
+            checked_dot((0..n).map(|k| (self.get_val(i, k), other.get_val(k, j))))
+        })
+    }
+
+    fn try_mul_vec(&self, v: &Self::VecIn) -> Result<Self::VecOut, MatrixErr> {
+        let (rows, cols) = self.dimensions();
+        let v = v.to_vec();
+        let out = (0..rows)
+            .map(|i| checked_dot((0..cols).map(|j| (self.get_val(i, j), v[j]))))
+            .collect::<Result<Vec<T>, _>>()?;
+        Ok(Self::VecOut::from_slice(&out)?)
+    }
+}
+
+// operations that only make sense for N x N matrices
+pub trait SquareMatrix<T: Scalar>: MatrixOps<T> {
+    // these panic on overflow like the arithmetic operators, try_determinant and try_minor
+    // report it instead. NaN and infinity come back as values rather than errors
     fn determinant(&self) -> T;
     fn minor(&self, i: usize, j: usize) -> T;
-    fn transposed(&self) -> Self;
-    fn add(&self, other: &Self) -> Self;
-    fn sub(&self, other: &Self) -> Self;
-    fn cross(&self, other: &Self) -> Self;
-    fn inverse(&self) -> Option<Self>;
-    fn mul_vec(&self, v: &Self::VEC) -> Self::VEC;
-    fn precision_eq(&self, other: &Self, precision: T) -> bool;
+
+    fn identity() -> Self {
+        Self::from_func(|i, j| if i == j { T::one() } else { T::default() })
+    }
+
+    fn cross(&self, other: &Self) -> Self {
+        let (n, _) = self.dimensions();
+        Self::from_func(|i, j| {
+            let mut val = T::default();
+            for k in 0..n {
+                val = val + self.get_val(i, k) * other.get_val(k, j)
+            }
+            val
+        })
+    }
+
+    // equal to its own adjoint, symmetric for real matrices
+    fn is_hermitian(&self, precision: T::Real) -> bool {
+        let (n, _) = self.dimensions();
+        (0..n).all(|i| {
+            (i..n).all(|j| self.get_val(i, j).distance(self.get_val(j, i).conj()) <= precision)
+        })
+    }
+
+    // adjoint * self is the identity, orthogonal for real matrices
+    fn is_unitary(&self, precision: T::Real) -> bool {
+        let (n, _) = self.dimensions();
+        (0..n).all(|i| {
+            (0..n).all(|j| {
+                let dot = (0..n).fold(T::default(), |acc, k| {
+                    acc + self.get_val(k, i).conj() * self.get_val(k, j)
+                });
+                let expected = if i == j { T::one() } else { T::default() };
+                dot.distance(expected) <= precision
+            })
+        })
+    }
+
+    // NotAField for integer and fixed-point matrices, see LU::new
+    fn lu(&self) -> Result<LU<T, Self>, MatrixErr>
+    where
+        T: SignedScalar,
+    {
+        LU::new(self)
+    }
+
+    fn qr(&self) -> QR<T, Self>
+    where
+        T: Float,
+    {
+        QR::new(self)
+    }
+
+    fn svd(&self) -> SVD<T, Self>
+    where
+        T: Float,
+    {
+        SVD::new(self)
+    }
+
+    fn cholesky(&self) -> Result<Cholesky<T, Self>, MatrixErr>
+    where
+        T: Scalar<Real = T> + Float,
+    {
+        Cholesky::new(self)
+    }
+
+    fn ldlt(&self) -> Result<LDLT<T, Self>, MatrixErr>
+    where
+        T: Scalar<Real = T> + PartialOrd,
+    {
+        LDLT::new(self)
+    }
+
+    fn try_determinant(&self) -> Result<T, MatrixErr> {
+        let (n, _) = self.dimensions();
+        let mut values: Vec<T> = (0..n * n).map(|k| self.get_val(k / n, k % n)).collect();
+        checked(determinant(&mut values, n))
+    }
+
+    fn try_minor(&self, i: usize, j: usize) -> Result<T, MatrixErr> {
+        let (n, _) = self.dimensions();
+        if i >= n || j >= n {
+            return Err(MatrixErr::IndexOutOfRange {
+                index: (i, j),
+                dimensions: (n, n),
+            });
+        }
+        let mut values: Vec<T> = (0..n * n)
+            .filter(|k| k / n != i && k % n != j)
+            .map(|k| self.get_val(k / n, k % n))
+            .collect();
+        checked(determinant(&mut values, n - 1))
+    }
+
+    // None for singular matrices, try_inverse says why
+    fn inverse(&self) -> Option<Self>
+    where
+        T: SignedScalar,
+    {
+        self.try_inverse().ok()
+    }
+
+    fn try_inverse(&self) -> Result<Self, MatrixErr>
+    where
+        T: SignedScalar,
+    {
+        // cofactors are only worth it when division truncates, elimination is stabler otherwise
+        if T::IS_FIELD {
+            return lu_inverse(self);
+        }
+
+        let det = self.try_determinant()?;
+        if det == T::default() {
+            return Err(MatrixErr::Singular);
+        }
+
+        Self::try_from_func(|i, j| {
+            let minor = self.try_minor(j, i)?;
+            let cofactor = if (i + j) % 2 == 0 {
+                minor
+            } else {
+                checked_neg(minor)?
+            };
+            exact_div(cofactor, det)
+        })
+    }
+
+    // solves self * x = b
+    fn solve(&self, b: &Self::VecOut) -> Result<Self::VecIn, MatrixErr>
+    where
+        T: SignedScalar,
+    {
+        let x = solve_columns(self, vec![b.to_vec()])?;
+        Ok(Self::VecIn::from_slice(&x[0])?)
+    }
+
+    // solves self * X = B for every column of B at once
+    fn solve_matrix<B>(&self, b: &B) -> Result<B, MatrixErr>
+    where
+        B: MatrixOps<T, VecOut = Self::VecOut>,
+        T: SignedScalar,
+    {
+        let (rows, cols) = b.dimensions();
+        let columns = (0..cols)
+            .map(|j| (0..rows).map(|i| b.get_val(i, j)).collect())
+            .collect();
+        let x = solve_columns(self, columns)?;
+        Ok(B::from_func(|i, j| x[j][i]))
+    }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct Matrix3x3<T: Scalar> {
-    _data: [T; 9],
+fn lu_inverse<T: SignedScalar, M: SquareMatrix<T>>(m: &M) -> Result<M, MatrixErr> {
+    let inv = m.lu()?.inverse().ok_or(MatrixErr::Singular)?;
+    M::try_from_func(|i, j| checked(Some(inv.get_val(i, j))))
 }
 
-impl<T: Scalar> Matrix3x3<T> {
-    pub fn new(data: [[T; 3]; 3]) -> Self {
-        Self::from_func(|i, j| data[i][j])
+// LU for floats, Cramer's rule otherwise so integer systems with integer solutions stay exact.
+// integer systems whose solution needs a fraction fail with NotExact, Rational solves those
+fn solve_columns<T: SignedScalar, M: SquareMatrix<T>>(
+    m: &M,
+    columns: Vec<Vec<T>>,
+) -> Result<Vec<Vec<T>>, MatrixErr> {
+    if T::IS_FIELD {
+        let lu = m.lu()?;
+        lu.check()?;
+        return Ok(columns.iter().map(|b| lu.solve_slice(b)).collect());
+    }
+
+    let det = m.try_determinant()?;
+    if det == T::default() {
+        return Err(MatrixErr::Singular);
     }
+    let (n, _) = m.dimensions();
+    columns
+        .iter()
+        .map(|b| {
+            (0..n)
+                .map(|i| {
+                    let sum = (0..n).try_fold(T::default(), |acc, j| {
+                        let c = checked(m.try_minor(j, i)?.checked_mul(b[j]))?;
+                        checked(if (i + j) % 2 == 0 {
+                            acc.checked_add(c)
+                        } else {
+                            acc.checked_sub(c)
+                        })
+                    })?;
+                    exact_div(sum, det)
+                })
+                .collect()
+        })
+        .collect()
 }
 
-impl<T: Scalar> Matrix<T> for Matrix3x3<T> {
-    type VEC = Vector3D<T>;
+// None means the operation overflowed, NaN and infinity are reported separately
+pub(crate) fn checked<T: Scalar>(val: Option<T>) -> Result<T, MatrixErr> {
+    match val {
+        None => Err(MatrixErr::Overflow),
+        Some(v) if !v.is_finite() => Err(MatrixErr::NonFinite),
+        Some(v) => Ok(v),
+    }
+}
 
-    fn zero() -> Self {
-        Self {
-            _data: [T::default(); 9],
+// num / den, integer types report a remainder instead of truncating it away
+pub(crate) fn exact_div<T: Scalar>(num: T, den: T) -> Result<T, MatrixErr> {
+    let q = checked(num.checked_div(den))?;
+    // q * den can't overflow, it is no further from zero than num
+    if T::IS_INTEGER && q * den != num {
+        return Err(MatrixErr::NotExact);
+    }
+    Ok(q)
+}
+
+pub(crate) fn checked_neg<T: Scalar>(val: T) -> Result<T, MatrixErr> {
+    checked(T::default().checked_sub(val))
+}
+
+fn checked_dot<T: Scalar>(mut pairs: impl Iterator<Item = (T, T)>) -> Result<T, MatrixErr> {
+    pairs.try_fold(T::default(), |acc, (a, b)| {
+        checked(a.checked_mul(b).and_then(|p| acc.checked_add(p)))
+    })
+}
+
+// generic R x C matrix, the fixed-size named matrices are thin wrappers around it
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixMN<T: Scalar, const R: usize, const C: usize> {
+    _data: [[T; C]; R],
+}
+
+impl<T: Scalar, const R: usize, const C: usize> MatrixMN<T, R, C> {
+    pub fn new(data: [[T; C]; R]) -> Self {
+        Self { _data: data }
+    }
+
+    // IndexMut without the panic
+    pub fn try_set_val(&mut self, i: usize, j: usize, val: T) -> Result<(), MatrixErr> {
+        if i >= R || j >= C {
+            return Err(MatrixErr::IndexOutOfRange {
+                index: (i, j),
+                dimensions: (R, C),
+            });
         }
+        self._data[i][j] = val;
+        Ok(())
     }
 
-    fn dimensions(&self) -> (usize, usize) {
-        (3, 3)
+    pub fn to_array(&self) -> [[T; C]; R] {
+        self._data
     }
 
-    fn identity() -> Self {
-        Self::from_func(|i, j| if i == j { T::one() } else { T::default() })
+    // matrix product, the shapes are checked at compile time
+    pub fn product<const K: usize>(&self, other: &MatrixMN<T, C, K>) -> MatrixMN<T, R, K> {
+        MatrixOps::product(self, other)
+    }
+
+    pub fn scale(&self, a: T) -> Self {
+        Self::from_func(|i, j| self._data[i][j] * a)
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> MatrixOps<T> for MatrixMN<T, R, C> {
+    type VecIn = VectorN<T, C>;
+    type VecOut = VectorN<T, R>;
+    type Transposed = MatrixMN<T, C, R>;
+
+    fn dimensions(&self) -> (usize, usize) {
+        (R, C)
     }
 
     fn from_func(f: impl Fn(usize, usize) -> T) -> Self {
-        let mut _data: [T; 9] = Default::default();
-        for i in 0..3 {
-            for j in 0..3 {
-                _data[i * 3 + j] = f(i, j);
-            }
+        Self {
+            _data: std::array::from_fn(|i| std::array::from_fn(|j| f(i, j))),
         }
-        Self { _data }
     }
 
     fn get_val(&self, i: usize, j: usize) -> T {
-        self._data[i * 3 + j]
+        self._data[i][j]
+    }
+
+    fn mul_vec(&self, v: &Self::VecIn) -> Self::VecOut {
+        VectorN::from_func(|i| {
+            self._data[i]
+                .iter()
+                .zip(v.data.iter())
+                .fold(T::default(), |acc, (a, b)| acc + *a * *b)
+        })
+    }
+
+    fn zero() -> Self {
+        Self {
+            _data: [[T::default(); C]; R],
+        }
     }
 
+    fn precision_eq(&self, other: &Self, precision: T::Real) -> bool {
+        self._data
+            .iter()
+            .flatten()
+            .zip(other._data.iter().flatten())
+            .all(|(s, o)| s.distance(*o) <= precision)
+    }
+}
+
+impl<T: Scalar, const N: usize> SquareMatrix<T> for MatrixMN<T, N, N> {
     fn determinant(&self) -> T {
-        // very cool code yes
-        let [a11, a12, a13, a21, a22, a23, a31, a32, a33] = self._data;
-        a11 * a22 * a33 + a12 * a23 * a31 + a13 * a21 * a32
-            - a13 * a22 * a31
-            - a12 * a21 * a33
-            - a11 * a23 * a32
+        let mut values: Vec<T> = self._data.iter().flatten().copied().collect();
+        determinant(&mut values, N).expect("Arithmetic overflow")
     }
 
     fn minor(&self, i: usize, j: usize) -> T {
-        let mut values = Vec::<T>::with_capacity(4);
-        for x in 0..3 {
-            for y in 0..3 {
+        assert!(i < N && j < N, "Index ({}, {}) out of range", i, j);
+        let mut values = Vec::<T>::with_capacity((N - 1) * (N - 1));
+        for x in 0..N {
+            for y in 0..N {
                 if x != i && y != j {
-                    values.push(self._data[x * 3 + y]);
+                    values.push(self._data[x][y]);
                 }
             }
         }
-        if values.len() != 4 {
-            panic!("Invalid matrix dimensions");
+
+        determinant(&mut values, N - 1).expect("Arithmetic overflow")
+    }
+
+    fn cross(&self, other: &Self) -> Self {
+        self.product(other)
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Index<(usize, usize)> for MatrixMN<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < R && j < C, "Index ({}, {}) out of range", i, j);
+        &self._data[i][j]
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> IndexMut<(usize, usize)> for MatrixMN<T, R, C> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < R && j < C, "Index ({}, {}) out of range", i, j);
+        &mut self._data[i][j]
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Add for MatrixMN<T, R, C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        MatrixOps::add(&self, &rhs)
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Add for &MatrixMN<T, R, C> {
+    type Output = MatrixMN<T, R, C>;
+
+    fn add(self, rhs: Self) -> MatrixMN<T, R, C> {
+        MatrixOps::add(self, rhs)
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Sub for MatrixMN<T, R, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        MatrixOps::sub(&self, &rhs)
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Sub for &MatrixMN<T, R, C> {
+    type Output = MatrixMN<T, R, C>;
+
+    fn sub(self, rhs: Self) -> MatrixMN<T, R, C> {
+        MatrixOps::sub(self, rhs)
+    }
+}
+
+impl<T: Scalar, const R: usize, const K: usize, const C: usize> Mul<MatrixMN<T, K, C>>
+    for MatrixMN<T, R, K>
+{
+    type Output = MatrixMN<T, R, C>;
+
+    fn mul(self, rhs: MatrixMN<T, K, C>) -> MatrixMN<T, R, C> {
+        self.product(&rhs)
+    }
+}
+
+impl<T: Scalar, const R: usize, const K: usize, const C: usize> Mul<&MatrixMN<T, K, C>>
+    for &MatrixMN<T, R, K>
+{
+    type Output = MatrixMN<T, R, C>;
+
+    fn mul(self, rhs: &MatrixMN<T, K, C>) -> MatrixMN<T, R, C> {
+        self.product(rhs)
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Mul<VectorN<T, C>> for MatrixMN<T, R, C> {
+    type Output = VectorN<T, R>;
+
+    fn mul(self, rhs: VectorN<T, C>) -> VectorN<T, R> {
+        self.mul_vec(&rhs)
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Mul<&VectorN<T, C>> for &MatrixMN<T, R, C> {
+    type Output = VectorN<T, R>;
+
+    fn mul(self, rhs: &VectorN<T, C>) -> VectorN<T, R> {
+        self.mul_vec(rhs)
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Mul<T> for MatrixMN<T, R, C> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        self.scale(rhs)
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Mul<T> for &MatrixMN<T, R, C> {
+    type Output = MatrixMN<T, R, C>;
+
+    fn mul(self, rhs: T) -> MatrixMN<T, R, C> {
+        self.scale(rhs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix2x2<T: Scalar> {
+    _inner: MatrixMN<T, 2, 2>,
+}
+
+impl<T: Scalar> Matrix2x2<T> {
+    pub fn new(data: [[T; 2]; 2]) -> Self {
+        Self {
+            _inner: MatrixMN::new(data),
+        }
+    }
+}
+
+impl<T: Scalar> MatrixOps<T> for Matrix2x2<T> {
+    type VecIn = Vector2D<T>;
+    type VecOut = Vector2D<T>;
+    type Transposed = Self;
+
+    fn dimensions(&self) -> (usize, usize) {
+        (2, 2)
+    }
+
+    fn from_func(f: impl Fn(usize, usize) -> T) -> Self {
+        Self {
+            _inner: MatrixMN::from_func(f),
         }
+    }
 
-        values[0] * values[3] - values[1] * values[2]
+    fn get_val(&self, i: usize, j: usize) -> T {
+        self._inner.get_val(i, j)
     }
 
-    fn transposed(&self) -> Self {
-        Self::from_func(|i, j| self.get_val(j, i))
+    fn mul_vec(&self, v: &Self::VecIn) -> Self::VecOut {
+        let [[a11, a12], [a21, a22]] = self._inner.to_array();
+        Vector2D::new(v.x * a11 + v.y * a12, v.x * a21 + v.y * a22)
     }
+}
 
-    fn add(&self, other: &Self) -> Self {
-        Self::from_func(|i, j| self.get_val(i, j) + other.get_val(i, j))
+impl<T: Scalar> SquareMatrix<T> for Matrix2x2<T> {
+    fn determinant(&self) -> T {
+        let [[a11, a12], [a21, a22]] = self._inner.to_array();
+        a11 * a22 - a12 * a21
     }
 
-    fn sub(&self, other: &Self) -> Self {
-        Self::from_func(|i, j| self.get_val(i, j) - other.get_val(i, j))
+    fn minor(&self, i: usize, j: usize) -> T {
+        assert!(i < 2 && j < 2, "Index ({}, {}) out of range", i, j);
+
+        // the minor of a 2x2 matrix is just the opposite element
+        self._inner.get_val(1 - i, 1 - j)
     }
 
-    fn cross(&self, other: &Self) -> Self {
-        Self::from_func(|i, j| {
-            let mut val = T::default();
-            for k in 0..3 {
-                val = val + self.get_val(i, k) * other.get_val(k, j)
-            }
-            val
+    fn try_inverse(&self) -> Result<Self, MatrixErr>
+    where
+        T: SignedScalar,
+    {
+        // the closed form det underflows long before the matrix is singular, [[1e-200, 0], [0, 1e-200]]
+        if T::IS_FIELD {
+            return lu_inverse(self);
+        }
+        let det = self.try_determinant()?;
+        let [[a11, a12], [a21, a22]] = self._inner.to_array();
+        // relative to the entries so small but well behaved matrices still invert, zero for exact types
+        let largest = max_norm(&[a11, a12, a21, a22]);
+        let two = T::Real::one() + T::Real::one();
+        if det.norm() <= T::machine_epsilon() * largest * largest * two {
+            return Err(MatrixErr::Singular);
+        }
+
+        let adjugate = [[a22, checked_neg(a12)?], [checked_neg(a21)?, a11]];
+        Self::try_from_func(|i, j| exact_div(adjugate[i][j], det))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix3x3<T: Scalar> {
+    _inner: MatrixMN<T, 3, 3>,
+}
+
+impl<T: Scalar> Matrix3x3<T> {
+    pub fn new(data: [[T; 3]; 3]) -> Self {
+        Self {
+            _inner: MatrixMN::new(data),
+        }
+    }
+
+    // the 2x2 sub-matrix left after removing row i and column j
+    pub fn minor_matrix(&self, i: usize, j: usize) -> Matrix2x2<T> {
+        assert!(i < 3 && j < 3, "Index ({}, {}) out of range", i, j);
+        self.minor_matrix_unchecked(i, j)
+    }
+
+    pub fn try_minor_matrix(&self, i: usize, j: usize) -> Result<Matrix2x2<T>, MatrixErr> {
+        if i >= 3 || j >= 3 {
+            return Err(MatrixErr::IndexOutOfRange {
+                index: (i, j),
+                dimensions: (3, 3),
+            });
+        }
+        Ok(self.minor_matrix_unchecked(i, j))
+    }
+
+    fn minor_matrix_unchecked(&self, i: usize, j: usize) -> Matrix2x2<T> {
+        Matrix2x2::from_func(|i1, j1| {
+            let x = if i1 < i { i1 } else { i1 + 1 };
+            let y = if j1 < j { j1 } else { j1 + 1 };
+            self._inner.get_val(x, y)
         })
     }
+}
+
+impl<T: Scalar> MatrixOps<T> for Matrix3x3<T> {
+    type VecIn = Vector3D<T>;
+    type VecOut = Vector3D<T>;
+    type Transposed = Self;
+
+    fn dimensions(&self) -> (usize, usize) {
+        (3, 3)
+    }
 
-    fn inverse(&self) -> Option<Self> {
-        let det = self.determinant();
-        // imagine if this panics lol that would be funny
-        if det.to_f32().unwrap().abs() < f32::EPSILON {
-            return None;
+    fn from_func(f: impl Fn(usize, usize) -> T) -> Self {
+        Self {
+            _inner: MatrixMN::from_func(f),
         }
+    }
 
-        Some(Matrix3x3::from_func(|i, j| {
-            if (i + j) % 2 == 0 {
-                self.minor(j, i) / det
-            } else {
-                -self.minor(j, i) / det
-            }
-        }))
+    fn get_val(&self, i: usize, j: usize) -> T {
+        self._inner.get_val(i, j)
     }
 
-    fn mul_vec(&self, v: &Self::VEC) -> Self::VEC {
-        let [a11, a12, a13, a21, a22, a23, a31, a32, a33] = self._data;
+    fn mul_vec(&self, v: &Self::VecIn) -> Self::VecOut {
+        let [[a11, a12, a13], [a21, a22, a23], [a31, a32, a33]] = self._inner.to_array();
         Vector3D::new(
             v.x * a11 + v.y * a12 + v.z * a13,
             v.x * a21 + v.y * a22 + v.z * a23,
             v.x * a31 + v.y * a32 + v.z * a33,
         )
     }
+}
 
-    fn precision_eq(&self, other: &Self, precision: T) -> bool {
-        for i in 0..3 {
-            for j in 0..3 {
-                if (self.get_val(i, j) - other.get_val(i, j)).abs() > precision {
-                    return false;
-                }
-            }
-        }
-        true
+impl<T: Scalar> SquareMatrix<T> for Matrix3x3<T> {
+    fn determinant(&self) -> T {
+        // very cool code yes
+        let [[a11, a12, a13], [a21, a22, a23], [a31, a32, a33]] = self._inner.to_array();
+        a11 * a22 * a33 + a12 * a23 * a31 + a13 * a21 * a32
+            - a13 * a22 * a31
+            - a12 * a21 * a33
+            - a11 * a23 * a32
+    }
+
+    fn minor(&self, i: usize, j: usize) -> T {
+        self.minor_matrix(i, j).determinant()
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Matrix4x4<T: Scalar> {
-    _data: [T; 16],
+    _inner: MatrixMN<T, 4, 4>,
 }
 
 impl<T: Scalar> Matrix4x4<T> {
     pub fn new(data: [[T; 4]; 4]) -> Self {
-        Self::from_func(|i, j| data[i][j])
+        Self {
+            _inner: MatrixMN::new(data),
+        }
     }
-}
 
-impl<T: Scalar> Matrix<T> for Matrix4x4<T> {
-    type VEC = Vector4D<T>;
+    // the 3x3 sub-matrix left after removing row i and column j
+    pub fn minor_matrix(&self, i: usize, j: usize) -> Matrix3x3<T> {
+        assert!(i < 4 && j < 4, "Index ({}, {}) out of range", i, j);
+        self.minor_matrix_unchecked(i, j)
+    }
 
-    fn zero() -> Self {
-        Self {
-            _data: [T::default(); 16],
+    pub fn try_minor_matrix(&self, i: usize, j: usize) -> Result<Matrix3x3<T>, MatrixErr> {
+        if i >= 4 || j >= 4 {
+            return Err(MatrixErr::IndexOutOfRange {
+                index: (i, j),
+                dimensions: (4, 4),
+            });
         }
+        Ok(self.minor_matrix_unchecked(i, j))
     }
 
-    fn dimensions(&self) -> (usize, usize) {
-        (4, 4)
+    fn minor_matrix_unchecked(&self, i: usize, j: usize) -> Matrix3x3<T> {
+        Matrix3x3::from_func(|i1, j1| {
+            let x = if i1 < i { i1 } else { i1 + 1 };
+            let y = if j1 < j { j1 } else { j1 + 1 };
+            self._inner.get_val(x, y)
+        })
     }
+}
 
-    fn identity() -> Self {
-        Self::from_func(|i, j| if i == j { T::one() } else { T::default() })
+impl<T: Scalar> MatrixOps<T> for Matrix4x4<T> {
+    type VecIn = Vector4D<T>;
+    type VecOut = Vector4D<T>;
+    type Transposed = Self;
+
+    fn dimensions(&self) -> (usize, usize) {
+        (4, 4)
     }
 
     fn from_func(f: impl Fn(usize, usize) -> T) -> Self {
-        let mut _data: [T; 16] = Default::default();
-        for i in 0..4 {
-            for j in 0..4 {
-                _data[i * 4 + j] = f(i, j);
-            }
+        Self {
+            _inner: MatrixMN::from_func(f),
         }
-        Self { _data }
     }
 
     fn get_val(&self, i: usize, j: usize) -> T {
-        self._data[i * 4 + j]
+        self._inner.get_val(i, j)
+    }
+
+    fn mul_vec(&self, v: &Self::VecIn) -> Self::VecOut {
+        self._inner.mul_vec(&v.clone().into()).into()
     }
+}
 
+impl<T: Scalar> SquareMatrix<T> for Matrix4x4<T> {
     fn determinant(&self) -> T {
+        // the 3x3 minors can be negative even when the whole determinant isn't, which unsigned
+        // types can't hold. Bareiss only goes through values it can represent
+        if T::IS_INTEGER {
+            return self._inner.determinant();
+        }
         let mut det_sum = T::default();
         for x in 0..4 {
             if x % 2 == 0 {
@@ -201,80 +799,190 @@ impl<T: Scalar> Matrix<T> for Matrix4x4<T> {
     }
 
     fn minor(&self, i: usize, j: usize) -> T {
-        let mut values = Vec::<T>::with_capacity(9);
-        for x in 0..4 {
-            for y in 0..4 {
-                if x != i && y != j {
-                    values.push(self._data[x * 4 + y]);
+        self.minor_matrix(i, j).determinant()
+    }
+}
+
+// rectangular matrices only need the generic operations, so one macro covers all of them
+macro_rules! impl_rect_matrix {
+    ($mat:ident, $r:literal, $c:literal, $vec_in:ident, $vec_out:ident, $transposed:ident) => {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $mat<T: Scalar> {
+            _inner: MatrixMN<T, $r, $c>,
+        }
+
+        impl<T: Scalar> $mat<T> {
+            pub fn new(data: [[T; $c]; $r]) -> Self {
+                Self {
+                    _inner: MatrixMN::new(data),
                 }
             }
         }
-        if values.len() != 9 {
-            panic!("Invalid matrix dimensions");
+
+        impl<T: Scalar> MatrixOps<T> for $mat<T> {
+            type VecIn = $vec_in<T>;
+            type VecOut = $vec_out<T>;
+            type Transposed = $transposed<T>;
+
+            fn dimensions(&self) -> (usize, usize) {
+                ($r, $c)
+            }
+
+            fn from_func(f: impl Fn(usize, usize) -> T) -> Self {
+                Self {
+                    _inner: MatrixMN::from_func(f),
+                }
+            }
+
+            fn get_val(&self, i: usize, j: usize) -> T {
+                self._inner.get_val(i, j)
+            }
+
+            fn mul_vec(&self, v: &Self::VecIn) -> Self::VecOut {
+                self._inner.mul_vec(&v.clone().into()).into()
+            }
         }
+    };
+}
 
-        Matrix3x3::from_func(|i1, j1| values[i1 * 3 + j1]).determinant()
-    }
+impl_rect_matrix!(Matrix2x3, 2, 3, Vector3D, Vector2D, Matrix3x2);
+impl_rect_matrix!(Matrix3x2, 3, 2, Vector2D, Vector3D, Matrix2x3);
+impl_rect_matrix!(Matrix3x4, 3, 4, Vector4D, Vector3D, Matrix4x3);
+impl_rect_matrix!(Matrix4x3, 4, 3, Vector3D, Vector4D, Matrix3x4);
+
+// operators and conversions for the named matrix types, both by value and by reference
+macro_rules! impl_matrix_ops {
+    ($mat:ident, $r:literal, $c:literal, $vec_in:ident, $vec_out:ident) => {
+        impl<T: Scalar> From<MatrixMN<T, $r, $c>> for $mat<T> {
+            fn from(m: MatrixMN<T, $r, $c>) -> Self {
+                Self { _inner: m }
+            }
+        }
 
-    fn transposed(&self) -> Self {
-        Self::from_func(|i, j| self.get_val(j, i))
-    }
+        impl<T: Scalar> From<$mat<T>> for MatrixMN<T, $r, $c> {
+            fn from(m: $mat<T>) -> Self {
+                m._inner
+            }
+        }
 
-    fn add(&self, other: &Self) -> Self {
-        Self::from_func(|i, j| self.get_val(i, j) + other.get_val(i, j))
-    }
+        impl<T: Scalar> Add for $mat<T> {
+            type Output = Self;
 
-    fn sub(&self, other: &Self) -> Self {
-        Self::from_func(|i, j| self.get_val(i, j) - other.get_val(i, j))
-    }
+            fn add(self, rhs: Self) -> Self {
+                MatrixOps::add(&self, &rhs)
+            }
+        }
 
-    fn cross(&self, other: &Self) -> Self {
-        Self::from_func(|i, j| {
-            let mut val = T::default();
-            for k in 0..4 {
-                val = val + self.get_val(i, k) * other.get_val(k, j)
+        impl<T: Scalar> Add for &$mat<T> {
+            type Output = $mat<T>;
+
+            fn add(self, rhs: Self) -> $mat<T> {
+                MatrixOps::add(self, rhs)
             }
-            val
-        })
-    }
+        }
+
+        impl<T: Scalar> Sub for $mat<T> {
+            type Output = Self;
 
-    fn inverse(&self) -> Option<Self> {
-        let det = self.determinant();
-        // imagine if this panics
-        if det.to_f32().unwrap().abs() < f32::EPSILON {
-            return None;
+            fn sub(self, rhs: Self) -> Self {
+                MatrixOps::sub(&self, &rhs)
+            }
         }
 
-        Some(Matrix4x4::from_func(|i, j| {
-            if (i + j) % 2 == 0 {
-                self.minor(j, i) / det
-            } else {
-                -self.minor(j, i) / det
+        impl<T: Scalar> Sub for &$mat<T> {
+            type Output = $mat<T>;
+
+            fn sub(self, rhs: Self) -> $mat<T> {
+                MatrixOps::sub(self, rhs)
             }
-        }))
-    }
+        }
+
+        impl<T: Scalar> Mul<$vec_in<T>> for $mat<T> {
+            type Output = $vec_out<T>;
 
-    fn mul_vec(&self, v: &Self::VEC) -> Self::VEC {
-        let vec_data = [v.x, v.y, v.z, v.w];
-        let mut output_data = [T::default(); 4];
-        for i in 0..4 {
-            for j in 0..4 {
-                output_data[i] = output_data[i] + vec_data[j] * self.get_val(i, j);
+            fn mul(self, rhs: $vec_in<T>) -> $vec_out<T> {
+                self.mul_vec(&rhs)
             }
         }
 
-        let [x, y, z, w] = output_data;
-        Vector4D::new(x, y, z, w)
-    }
+        impl<T: Scalar> Mul<&$vec_in<T>> for &$mat<T> {
+            type Output = $vec_out<T>;
 
-    fn precision_eq(&self, other: &Self, precision: T) -> bool {
-        for i in 0..4 {
-            for j in 0..4 {
-                if (self.get_val(i, j) - other.get_val(i, j)).abs() > precision {
-                    return false;
+            fn mul(self, rhs: &$vec_in<T>) -> $vec_out<T> {
+                self.mul_vec(rhs)
+            }
+        }
+
+        impl<T: Scalar> Mul<T> for $mat<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                Self {
+                    _inner: self._inner.scale(rhs),
                 }
             }
         }
-        true
-    }
+
+        impl<T: Scalar> Mul<T> for &$mat<T> {
+            type Output = $mat<T>;
+
+            fn mul(self, rhs: T) -> $mat<T> {
+                $mat {
+                    _inner: self._inner.scale(rhs),
+                }
+            }
+        }
+
+        impl<T: Scalar> Index<(usize, usize)> for $mat<T> {
+            type Output = T;
+
+            fn index(&self, idx: (usize, usize)) -> &T {
+                &self._inner[idx]
+            }
+        }
+
+        impl<T: Scalar> IndexMut<(usize, usize)> for $mat<T> {
+            fn index_mut(&mut self, idx: (usize, usize)) -> &mut T {
+                &mut self._inner[idx]
+            }
+        }
+
+        impl<T: Scalar> $mat<T> {
+            // IndexMut without the panic
+            pub fn try_set_val(&mut self, i: usize, j: usize, val: T) -> Result<(), MatrixErr> {
+                self._inner.try_set_val(i, j, val)
+            }
+        }
+    };
+}
+
+// square matrices can also be multiplied with each other
+macro_rules! impl_square_matrix_ops {
+    ($mat:ident, $n:literal, $vec:ident) => {
+        impl_matrix_ops!($mat, $n, $n, $vec, $vec);
+
+        impl<T: Scalar> Mul for $mat<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                self.cross(&rhs)
+            }
+        }
+
+        impl<T: Scalar> Mul for &$mat<T> {
+            type Output = $mat<T>;
+
+            fn mul(self, rhs: Self) -> $mat<T> {
+                self.cross(rhs)
+            }
+        }
+    };
 }
+
+impl_square_matrix_ops!(Matrix2x2, 2, Vector2D);
+impl_square_matrix_ops!(Matrix3x3, 3, Vector3D);
+impl_square_matrix_ops!(Matrix4x4, 4, Vector4D);
+impl_matrix_ops!(Matrix2x3, 2, 3, Vector3D, Vector2D);
+impl_matrix_ops!(Matrix3x2, 3, 2, Vector2D, Vector3D);
+impl_matrix_ops!(Matrix3x4, 3, 4, Vector4D, Vector3D);
+impl_matrix_ops!(Matrix4x3, 4, 3, Vector3D, Vector4D);
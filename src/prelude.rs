@@ -0,0 +1,20 @@
+// everything you need for `use yelmath::prelude::*;`
+pub use crate::camera::{DepthRange, Handedness};
+pub use crate::cholesky::{Cholesky, LDLT};
+pub use crate::complex::Complex;
+pub use crate::dual::Dual;
+pub use crate::dynamic::{DMatrix, DVector};
+pub use crate::errors::{MathErr, MatrixErr, VectorErr};
+pub use crate::fixed::{Q16_16, Q32_32};
+pub use crate::interval::Interval;
+pub use crate::lu::LU;
+pub use crate::matrices::{
+    Matrix2x2, Matrix2x3, Matrix3x2, Matrix3x3, Matrix3x4, Matrix4x3, Matrix4x4, MatrixMN, MatrixOps,
+    SquareMatrix,
+};
+pub use crate::qr::QR;
+pub use crate::quaternion::Quaternion;
+pub use crate::rational::Rational;
+pub use crate::svd::SVD;
+pub use crate::types::{RealScalar, Scalar, SignedScalar, Sqrt};
+pub use crate::vectors::{Vector, Vector2D, Vector3D, Vector4D, VectorN};
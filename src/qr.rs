@@ -0,0 +1,132 @@
+use crate::errors::MatrixErr;
+use crate::matrices::SquareMatrix;
+use crate::types::Scalar;
+use crate::vectors::Vector;
+use num_traits::Float;
+use std::marker::PhantomData;
+
+// A = QR with Householder reflections, Q is orthogonal (rows x rows) and R upper triangular (rows x cols)
+#[derive(Debug, Clone, PartialEq)]
+pub struct QR<T: Scalar, M> {
+    _rows: usize,
+    _cols: usize,
+    _q: Vec<T>,
+    _r: Vec<T>,
+    _matrix: PhantomData<M>,
+}
+
+impl<T: Scalar + Float, M> QR<T, M> {
+    pub(crate) fn from_rows(rows: usize, cols: usize, mut r: Vec<T>) -> Self {
+        let two = T::one() + T::one();
+        let mut q: Vec<T> = (0..rows * rows)
+            .map(|i| {
+                if i / rows == i % rows {
+                    T::one()
+                } else {
+                    T::zero()
+                }
+            })
+            .collect();
+
+        for k in 0..cols.min(rows.saturating_sub(1)) {
+            // reflect column k below the diagonal onto -sign(x0) * |x| e0, the sign avoids cancellation
+            let mut v: Vec<T> = (k..rows).map(|i| r[i * cols + k]).collect();
+            let norm = v.iter().fold(T::zero(), |acc, x| acc + *x * *x).sqrt();
+            if norm == T::zero() {
+                continue;
+            }
+            let alpha = if v[0] < T::zero() { norm } else { -norm };
+            v[0] = v[0] - alpha;
+            let v_sq = v.iter().fold(T::zero(), |acc, x| acc + *x * *x);
+            if v_sq == T::zero() {
+                continue;
+            }
+
+            // R = H R
+            for j in k..cols {
+                let s = (0..v.len()).fold(T::zero(), |acc, i| acc + v[i] * r[(k + i) * cols + j]);
+                let f = two * s / v_sq;
+                for (i, vi) in v.iter().enumerate() {
+                    r[(k + i) * cols + j] = r[(k + i) * cols + j] - f * *vi;
+                }
+            }
+            // Q = Q H
+            for row in 0..rows {
+                let s = (0..v.len()).fold(T::zero(), |acc, i| acc + q[row * rows + k + i] * v[i]);
+                let f = two * s / v_sq;
+                for (i, vi) in v.iter().enumerate() {
+                    q[row * rows + k + i] = q[row * rows + k + i] - f * *vi;
+                }
+            }
+            // clean out the rounding noise the reflection leaves below the diagonal
+            r[k * cols + k] = alpha;
+            for i in k + 1..rows {
+                r[i * cols + k] = T::zero();
+            }
+        }
+
+        Self {
+            _rows: rows,
+            _cols: cols,
+            _q: q,
+            _r: r,
+            _matrix: PhantomData,
+        }
+    }
+
+    pub(crate) fn into_parts(self) -> (Vec<T>, Vec<T>) {
+        (self._q, self._r)
+    }
+
+    // x minimizing |Ax - b|, needs at least as many rows as columns and full column rank
+    pub(crate) fn least_squares(&self, b: &[T]) -> Result<Vec<T>, MatrixErr> {
+        let (rows, cols) = (self._rows, self._cols);
+        // underdetermined, a shape problem rather than a rank one
+        if rows < cols {
+            return Err(MatrixErr::DimensionErr {
+                expected: (cols, cols),
+                got: (rows, cols),
+            });
+        }
+
+        let max_diag = (0..cols).fold(T::zero(), |acc, k| acc.max(self._r[k * cols + k].abs()));
+        let tolerance = max_diag * T::from(rows).unwrap() * T::epsilon();
+
+        // Q^T b, only the first cols entries matter
+        let mut x: Vec<T> = (0..cols)
+            .map(|k| (0..rows).fold(T::zero(), |acc, i| acc + self._q[i * rows + k] * b[i]))
+            .collect();
+        for i in (0..cols).rev() {
+            let d = self._r[i * cols + i];
+            if d.abs() <= tolerance {
+                return Err(MatrixErr::Singular);
+            }
+            for k in i + 1..cols {
+                x[i] = x[i] - self._r[i * cols + k] * x[k];
+            }
+            x[i] = x[i] / d;
+        }
+        Ok(x)
+    }
+}
+
+impl<T: Scalar + Float, M: SquareMatrix<T>> QR<T, M> {
+    pub fn new(m: &M) -> Self {
+        let (n, _) = m.dimensions();
+        let data = (0..n * n).map(|i| m.get_val(i / n, i % n)).collect();
+        Self::from_rows(n, n, data)
+    }
+
+    pub fn q(&self) -> M {
+        M::from_func(|i, j| self._q[i * self._rows + j])
+    }
+
+    pub fn r(&self) -> M {
+        M::from_func(|i, j| self._r[i * self._cols + j])
+    }
+
+    pub fn solve_least_squares(&self, b: &M::VecOut) -> Result<M::VecIn, MatrixErr> {
+        let x = self.least_squares(&b.to_vec())?;
+        Ok(M::VecIn::from_slice(&x)?)
+    }
+}
@@ -0,0 +1,296 @@
+use crate::matrices::{Matrix3x3, Matrix4x4, MatrixOps};
+use crate::types::Scalar;
+use crate::vectors::{Vector, Vector3D};
+use num_traits::Float;
+use std::ops::{Mul, MulAssign};
+
+// w + xi + yj + zk, only makes sense for float scalars
+#[derive(PartialEq, Clone, Debug)]
+pub struct Quaternion<T> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Scalar + Float> Quaternion<T> {
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self { w, x, y, z }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+
+    // rotation of `angle` radians around `axis`, the axis doesn't have to be unit length
+    pub fn from_axis_angle(axis: &Vector3D<T>, angle: T) -> Self {
+        let len = axis.dot(axis).sqrt();
+        if len == T::zero() {
+            return Self::identity();
+        }
+
+        let half = angle / (T::one() + T::one());
+        let s = half.sin() / len;
+        Self::new(half.cos(), axis.x * s, axis.y * s, axis.z * s)
+    }
+
+    // (axis, angle) of a unit quaternion, the axis is x for the identity rotation
+    pub fn to_axis_angle(&self) -> (Vector3D<T>, T) {
+        let q = self.normalized();
+        let s = (T::one() - q.w * q.w).max(T::zero()).sqrt();
+        let angle = (T::one() + T::one()) * q.w.min(T::one()).max(-T::one()).acos();
+        if s < T::epsilon() {
+            return (Vector3D::new(T::one(), T::zero(), T::zero()), angle);
+        }
+        (Vector3D::new(q.x / s, q.y / s, q.z / s), angle)
+    }
+
+    pub fn vector_part(&self) -> Vector3D<T> {
+        Vector3D::new(self.x, self.y, self.z)
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn magnitude(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        // any other size inverts fine, a tolerance here would throw away small but valid quaternions
+        let norm_sq = self.dot(self);
+        if norm_sq == T::zero() || !Float::is_finite(norm_sq) {
+            return None;
+        }
+
+        let c = self.conjugate();
+        Some(Self::new(c.w / norm_sq, c.x / norm_sq, c.y / norm_sq, c.z / norm_sq))
+    }
+
+    pub fn normalized(&self) -> Self {
+        let len = self.magnitude();
+        if len == T::zero() {
+            return Self::identity();
+        }
+        Self::new(self.w / len, self.x / len, self.y / len, self.z / len)
+    }
+
+    // Hamilton product, applies `other` first and then `self`
+    pub fn hamilton(&self, other: &Self) -> Self {
+        Self::new(
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        )
+    }
+
+    // q * v * q^-1, assumes a unit quaternion
+    pub fn rotate(&self, v: &Vector3D<T>) -> Vector3D<T> {
+        let two = T::one() + T::one();
+        let u = self.vector_part();
+        let t = u.cross(v).scale(two);
+        v.add(&t.scale(self.w)).add(&u.cross(&t))
+    }
+
+    // normalized linear interpolation, cheap but not constant speed
+    pub fn nlerp(&self, other: &Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() {
+            Self::new(-other.w, -other.x, -other.y, -other.z)
+        } else {
+            other.clone()
+        };
+        let s = T::one() - t;
+        Self::new(
+            self.w * s + other.w * t,
+            self.x * s + other.x * t,
+            self.y * s + other.y * t,
+            self.z * s + other.z * t,
+        )
+        .normalized()
+    }
+
+    // spherical linear interpolation, always takes the shorter arc
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let mut cos_theta = self.dot(other);
+        let other = if cos_theta < T::zero() {
+            cos_theta = -cos_theta;
+            Self::new(-other.w, -other.x, -other.y, -other.z)
+        } else {
+            other.clone()
+        };
+
+        // almost parallel, sin(theta) would blow up
+        if cos_theta > T::one() - T::epsilon().sqrt() {
+            return self.nlerp(&other, t);
+        }
+
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let a = ((T::one() - t) * theta).sin() / sin_theta;
+        let b = (t * theta).sin() / sin_theta;
+        Self::new(
+            self.w * a + other.w * b,
+            self.x * a + other.x * b,
+            self.y * a + other.y * b,
+            self.z * a + other.z * b,
+        )
+    }
+
+    pub fn precision_eq(&self, other: &Self, precision: T) -> bool {
+        let cmp_vals = [
+            (self.w, other.w),
+            (self.x, other.x),
+            (self.y, other.y),
+            (self.z, other.z),
+        ];
+        cmp_vals.iter().all(|(s, o)| (*s - *o).abs() <= precision)
+    }
+
+    pub fn to_matrix3x3(&self) -> Matrix3x3<T> {
+        let q = self.normalized();
+        let two = T::one() + T::one();
+        let (w, x, y, z) = (q.w, q.x, q.y, q.z);
+        Matrix3x3::new([
+            [
+                T::one() - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                T::one() - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                T::one() - two * (x * x + y * y),
+            ],
+        ])
+    }
+
+    pub fn to_matrix4x4(&self) -> Matrix4x4<T> {
+        let m = self.to_matrix3x3();
+        Matrix4x4::from_func(|i, j| match (i, j) {
+            (3, 3) => T::one(),
+            (3, _) | (_, 3) => T::zero(),
+            _ => m.get_val(i, j),
+        })
+    }
+
+    // Shepperd's method, picks the largest diagonal term to stay stable
+    pub fn from_matrix3x3(m: &Matrix3x3<T>) -> Self {
+        let one = T::one();
+        let two = one + one;
+        let quarter = one / (two * two);
+        let (m00, m11, m22) = (m.get_val(0, 0), m.get_val(1, 1), m.get_val(2, 2));
+        let trace = m00 + m11 + m22;
+
+        if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Self::new(
+                quarter * s,
+                (m.get_val(2, 1) - m.get_val(1, 2)) / s,
+                (m.get_val(0, 2) - m.get_val(2, 0)) / s,
+                (m.get_val(1, 0) - m.get_val(0, 1)) / s,
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = (one + m00 - m11 - m22).sqrt() * two;
+            Self::new(
+                (m.get_val(2, 1) - m.get_val(1, 2)) / s,
+                quarter * s,
+                (m.get_val(0, 1) + m.get_val(1, 0)) / s,
+                (m.get_val(0, 2) + m.get_val(2, 0)) / s,
+            )
+        } else if m11 > m22 {
+            let s = (one + m11 - m00 - m22).sqrt() * two;
+            Self::new(
+                (m.get_val(0, 2) - m.get_val(2, 0)) / s,
+                (m.get_val(0, 1) + m.get_val(1, 0)) / s,
+                quarter * s,
+                (m.get_val(1, 2) + m.get_val(2, 1)) / s,
+            )
+        } else {
+            let s = (one + m22 - m00 - m11).sqrt() * two;
+            Self::new(
+                (m.get_val(1, 0) - m.get_val(0, 1)) / s,
+                (m.get_val(0, 2) + m.get_val(2, 0)) / s,
+                (m.get_val(1, 2) + m.get_val(2, 1)) / s,
+                quarter * s,
+            )
+        }
+    }
+
+    // only the upper-left 3x3 rotation block is used
+    pub fn from_matrix4x4(m: &Matrix4x4<T>) -> Self {
+        Self::from_matrix3x3(&m.minor_matrix(3, 3))
+    }
+}
+
+impl<T: Scalar + Float> Mul for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.hamilton(&rhs)
+    }
+}
+
+impl<T: Scalar + Float> Mul for &Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: Self) -> Quaternion<T> {
+        self.hamilton(rhs)
+    }
+}
+
+impl<T: Scalar + Float> Mul<Vector3D<T>> for Quaternion<T> {
+    type Output = Vector3D<T>;
+
+    fn mul(self, rhs: Vector3D<T>) -> Vector3D<T> {
+        self.rotate(&rhs)
+    }
+}
+
+impl<T: Scalar + Float> Mul<&Vector3D<T>> for &Quaternion<T> {
+    type Output = Vector3D<T>;
+
+    fn mul(self, rhs: &Vector3D<T>) -> Vector3D<T> {
+        self.rotate(rhs)
+    }
+}
+
+impl<T: Scalar + Float> MulAssign for Quaternion<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.hamilton(&rhs);
+    }
+}
+
+impl<T: Scalar + Float> From<Quaternion<T>> for Matrix3x3<T> {
+    fn from(q: Quaternion<T>) -> Self {
+        q.to_matrix3x3()
+    }
+}
+
+impl<T: Scalar + Float> From<Quaternion<T>> for Matrix4x4<T> {
+    fn from(q: Quaternion<T>) -> Self {
+        q.to_matrix4x4()
+    }
+}
+
+impl<T: Scalar + Float> From<Matrix3x3<T>> for Quaternion<T> {
+    fn from(m: Matrix3x3<T>) -> Self {
+        Quaternion::from_matrix3x3(&m)
+    }
+}
+
+impl<T: Scalar + Float> From<Matrix4x4<T>> for Quaternion<T> {
+    fn from(m: Matrix4x4<T>) -> Self {
+        Quaternion::from_matrix4x4(&m)
+    }
+}
@@ -0,0 +1,414 @@
+use crate::matrices::{
+    Matrix2x2, Matrix2x3, Matrix3x2, Matrix3x3, Matrix3x4, Matrix4x3, Matrix4x4, MatrixMN,
+    MatrixOps,
+};
+use crate::types::Scalar;
+use crate::vectors::{Vector2D, Vector3D, Vector4D, VectorN};
+use num_traits::{Num, One, Signed, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+// exact fraction, always kept reduced with a positive denominator so == can compare the fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<I> {
+    _num: I,
+    _den: I,
+}
+
+impl<I: Scalar + Signed + Ord> Rational<I> {
+    // panics on a zero denominator, like integer division does, and on I::MIN
+    pub fn new(num: I, den: I) -> Self {
+        assert!(den != I::zero(), "Denominator is zero");
+        Self::try_new(num, den).expect("Arithmetic overflow")
+    }
+
+    // None for a zero denominator and for I::MIN, which has no negation so it can't be normalized
+    pub fn try_new(num: I, den: I) -> Option<Self> {
+        if den == I::zero() || !negatable(num) || !negatable(den) {
+            return None;
+        }
+
+        let g = gcd(num, den);
+        let (num, den) = (num / g, den / g);
+        if den < I::zero() {
+            Some(Self {
+                _num: -num,
+                _den: -den,
+            })
+        } else {
+            Some(Self {
+                _num: num,
+                _den: den,
+            })
+        }
+    }
+
+    // panics on I::MIN like new
+    pub fn from_integer(n: I) -> Self {
+        Self::new(n, I::one())
+    }
+
+    pub fn numer(&self) -> I {
+        self._num
+    }
+
+    pub fn denom(&self) -> I {
+        self._den
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self._den == I::one()
+    }
+
+    pub fn recip(&self) -> Self {
+        Self::new(self._den, self._num)
+    }
+}
+
+// false only for I::MIN
+fn negatable<I: Scalar>(n: I) -> bool {
+    I::default().checked_sub(n).is_some()
+}
+
+// never sees I::MIN, so abs can't overflow
+fn gcd<I: Scalar + Signed + Ord>(a: I, b: I) -> I {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != I::zero() {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+impl<I: Scalar + Signed + Ord> From<I> for Rational<I> {
+    fn from(n: I) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl<I: Scalar + Signed + Ord> Default for Rational<I> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<I: Scalar + Signed + Ord + Display> Display for Rational<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self._num)
+        } else {
+            write!(f, "{}/{}", self._num, self._den)
+        }
+    }
+}
+
+impl<I: Scalar + Signed + Ord> PartialOrd for Rational<I> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I: Scalar + Signed + Ord> Ord for Rational<I> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_fractions(self._num, self._den, other._num, other._den)
+    }
+}
+
+// a / b against c / d with positive denominators. cross multiplying keeps the order, when the
+// products overflow the integer parts are compared instead and then the leftover fractions,
+// flipped over so they shrink like in a continued fraction
+fn cmp_fractions<I: Scalar + Signed + Ord>(a: I, b: I, c: I, d: I) -> Ordering {
+    let (mut a, mut b, mut c, mut d) = (a, b, c, d);
+    loop {
+        if let (Some(ad), Some(cb)) = (a.checked_mul(d), c.checked_mul(b)) {
+            return ad.cmp(&cb);
+        }
+        let (q1, r1) = floor_div(a, b);
+        let (q2, r2) = floor_div(c, d);
+        if q1 != q2 {
+            return q1.cmp(&q2);
+        }
+        // both remainders are in [0, 1), r1 / b < r2 / d exactly when d / r2 < b / r1
+        match (r1 == I::zero(), r2 == I::zero()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => (a, b, c, d) = (d, r2, b, r1),
+        }
+    }
+}
+
+// quotient rounded down and the remainder that goes with it, b is positive
+fn floor_div<I: Scalar + Signed + Ord>(a: I, b: I) -> (I, I) {
+    let (q, r) = (a / b, a % b);
+    if r < I::zero() {
+        (q - I::one(), r + b)
+    } else {
+        (q, r)
+    }
+}
+
+impl<I: Scalar + Signed + Ord> Add for Rational<I> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("Arithmetic overflow")
+    }
+}
+
+impl<I: Scalar + Signed + Ord> Sub for Rational<I> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("Arithmetic overflow")
+    }
+}
+
+impl<I: Scalar + Signed + Ord> Mul for Rational<I> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).expect("Arithmetic overflow")
+    }
+}
+
+impl<I: Scalar + Signed + Ord> Div for Rational<I> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        if rhs._num == I::zero() {
+            panic!("Division by zero");
+        }
+        self.checked_div(rhs).expect("Arithmetic overflow")
+    }
+}
+
+// what's left after taking out a whole number of rhs, truncating towards zero like integers do
+impl<I: Scalar + Signed + Ord> Rem for Rational<I> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        let q = self / rhs;
+        self - rhs * Self::from_integer(q._num / q._den)
+    }
+}
+
+impl<I: Scalar + Signed + Ord> Neg for Rational<I> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            _num: -self._num,
+            _den: self._den,
+        }
+    }
+}
+
+impl<I: Scalar + Signed + Ord> Zero for Rational<I> {
+    fn zero() -> Self {
+        Self::from_integer(I::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self._num == I::zero()
+    }
+}
+
+impl<I: Scalar + Signed + Ord> One for Rational<I> {
+    fn one() -> Self {
+        Self::from_integer(I::one())
+    }
+}
+
+impl<I: Scalar + Signed + Ord> Num for Rational<I> {
+    type FromStrRadixErr = ParseRationalErr<I::FromStrRadixErr>;
+
+    // "3/4" or plain "3"
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let (num, den) = match str.split_once('/') {
+            Some((num, den)) => (num, den),
+            None => (str, "1"),
+        };
+        let num = I::from_str_radix(num.trim(), radix).map_err(ParseRationalErr::Int)?;
+        let den = I::from_str_radix(den.trim(), radix).map_err(ParseRationalErr::Int)?;
+        if den == I::zero() {
+            return Err(ParseRationalErr::ZeroDenominator);
+        }
+        Self::try_new(num, den).ok_or(ParseRationalErr::OutOfRange)
+    }
+}
+
+impl<I: Scalar + Signed + Ord> Signed for Rational<I> {
+    fn abs(&self) -> Self {
+        Self {
+            _num: self._num.abs(),
+            _den: self._den,
+        }
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            Self::zero()
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        Self::from_integer(self._num.signum())
+    }
+
+    fn is_positive(&self) -> bool {
+        self._num > I::zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        self._num < I::zero()
+    }
+}
+
+impl<I: Scalar + Signed + Ord> ToPrimitive for Rational<I> {
+    // the integer conversions truncate towards zero
+    fn to_i64(&self) -> Option<i64> {
+        (self._num / self._den).to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        (self._num / self._den).to_u64()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        Some(self._num.to_f32()? / self._den.to_f32()?)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self._num.to_f64()? / self._den.to_f64()?)
+    }
+}
+
+impl<I: Scalar + Signed + Ord> Scalar for Rational<I> {
+    // division is exact and so is everything else, rounding tolerances end up as zero
+    type Real = Self;
+
+    const IS_FIELD: bool = true;
+
+    fn norm(self) -> Self {
+        Signed::abs(&self)
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        let g = gcd(self._den, rhs._den);
+        let num = self
+            ._num
+            .checked_mul(rhs._den / g)?
+            .checked_add(rhs._num.checked_mul(self._den / g)?)?;
+        Self::try_new(num, (self._den / g).checked_mul(rhs._den)?)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // cancel across first so the products stay as small as possible
+        let g1 = gcd(self._num, rhs._den);
+        let g2 = gcd(rhs._num, self._den);
+        let (g1, g2) = (g1.max(I::one()), g2.max(I::one()));
+        let num = (self._num / g1).checked_mul(rhs._num / g2)?;
+        let den = (self._den / g2).checked_mul(rhs._den / g1)?;
+        Self::try_new(num, den)
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs._num == I::zero() {
+            return None;
+        }
+        self.checked_mul(rhs.recip())
+    }
+}
+
+pub enum ParseRationalErr<E> {
+    Int(E),
+    ZeroDenominator,
+    OutOfRange,
+}
+
+impl<E: Debug> Debug for ParseRationalErr<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseRationalErr::Int(err) => write!(f, "{:?}", err),
+            ParseRationalErr::ZeroDenominator => write!(f, "Denominator is zero"),
+            ParseRationalErr::OutOfRange => write!(f, "Rational number out of range"),
+        }
+    }
+}
+
+impl<E: Display> Display for ParseRationalErr<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseRationalErr::Int(err) => write!(f, "{}", err),
+            ParseRationalErr::ZeroDenominator => write!(f, "Denominator is zero"),
+            ParseRationalErr::OutOfRange => write!(f, "Rational number out of range"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ParseRationalErr<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseRationalErr::Int(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// integer matrices and vectors convert element by element so they can be inverted and solved exactly
+macro_rules! impl_rational_matrix_conversion {
+    ($($mat:ident),*) => {
+        $(
+            impl<I: Scalar + Signed + Ord> From<$mat<I>> for $mat<Rational<I>> {
+                fn from(m: $mat<I>) -> Self {
+                    Self::from_func(|i, j| Rational::from_integer(m.get_val(i, j)))
+                }
+            }
+        )*
+    };
+}
+
+impl_rational_matrix_conversion!(
+    Matrix2x2, Matrix3x3, Matrix4x4, Matrix2x3, Matrix3x2, Matrix3x4, Matrix4x3
+);
+
+impl<I: Scalar + Signed + Ord, const R: usize, const C: usize> From<MatrixMN<I, R, C>>
+    for MatrixMN<Rational<I>, R, C>
+{
+    fn from(m: MatrixMN<I, R, C>) -> Self {
+        Self::from_func(|i, j| Rational::from_integer(m.get_val(i, j)))
+    }
+}
+
+impl<I: Scalar + Signed + Ord> From<Vector2D<I>> for Vector2D<Rational<I>> {
+    fn from(v: Vector2D<I>) -> Self {
+        Vector2D::new(v.x.into(), v.y.into())
+    }
+}
+
+impl<I: Scalar + Signed + Ord> From<Vector3D<I>> for Vector3D<Rational<I>> {
+    fn from(v: Vector3D<I>) -> Self {
+        Vector3D::new(v.x.into(), v.y.into(), v.z.into())
+    }
+}
+
+impl<I: Scalar + Signed + Ord> From<Vector4D<I>> for Vector4D<Rational<I>> {
+    fn from(v: Vector4D<I>) -> Self {
+        Vector4D::new(v.x.into(), v.y.into(), v.z.into(), v.w.into())
+    }
+}
+
+impl<I: Scalar + Signed + Ord, const N: usize> From<VectorN<I, N>> for VectorN<Rational<I>, N> {
+    fn from(v: VectorN<I, N>) -> Self {
+        VectorN::new(v.data.map(Rational::from_integer))
+    }
+}
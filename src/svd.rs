@@ -0,0 +1,192 @@
+use crate::matrices::SquareMatrix;
+use crate::types::Scalar;
+use crate::util::MAX_SWEEPS;
+use crate::vectors::Vector;
+use num_traits::Float;
+use std::marker::PhantomData;
+
+// A = U * Σ * V^T with U, V orthogonal and the singular values in Σ sorted from largest to smallest
+#[derive(Debug, Clone, PartialEq)]
+pub struct SVD<T: Scalar, M> {
+    _n: usize,
+    _u: Vec<T>,
+    _sigma: Vec<T>,
+    _v: Vec<T>,
+    _matrix: PhantomData<M>,
+}
+
+impl<T: Scalar + Float, M: SquareMatrix<T>> SVD<T, M> {
+    // one-sided jacobi (Hestenes), rotates pairs of columns until they are orthogonal,
+    // works on A directly so the accuracy doesn't suffer like going through A^T A would
+    pub fn new(m: &M) -> Self {
+        let (n, _) = m.dimensions();
+        let mut u: Vec<T> = (0..n * n).map(|k| m.get_val(k / n, k % n)).collect();
+        let mut v: Vec<T> = (0..n * n)
+            .map(|k| if k / n == k % n { T::one() } else { T::zero() })
+            .collect();
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+                    for k in 0..n {
+                        let (up, uq) = (u[k * n + p], u[k * n + q]);
+                        alpha = alpha + up * up;
+                        beta = beta + uq * uq;
+                        gamma = gamma + up * uq;
+                    }
+                    if gamma == T::zero() || gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (gamma + gamma);
+                    let t = zeta.signum() / (zeta.abs() + (zeta * zeta + T::one()).sqrt());
+                    let c = T::one() / (t * t + T::one()).sqrt();
+                    let s = c * t;
+                    for w in [&mut u, &mut v] {
+                        for k in 0..n {
+                            let (wp, wq) = (w[k * n + p], w[k * n + q]);
+                            w[k * n + p] = c * wp - s * wq;
+                            w[k * n + q] = s * wp + c * wq;
+                        }
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        // the columns of U are now orthogonal, their lengths are the singular values
+        let norms: Vec<T> = (0..n)
+            .map(|j| {
+                (0..n)
+                    .fold(T::zero(), |acc, k| acc + u[k * n + j] * u[k * n + j])
+                    .sqrt()
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| {
+            norms[j]
+                .partial_cmp(&norms[i])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let sigma: Vec<T> = order.iter().map(|&j| norms[j]).collect();
+        let v = (0..n * n).map(|k| v[(k / n) * n + order[k % n]]).collect();
+
+        // columns that belong to (numerically) zero singular values carry no direction,
+        // they get filled in afterwards so that U stays orthogonal
+        let size = T::from(n).unwrap();
+        let tiny = sigma.first().copied().unwrap_or(T::zero()) * size * T::epsilon();
+        let mut columns: Vec<Vec<T>> = Vec::with_capacity(n);
+        for (j, s) in order.iter().zip(sigma.iter()) {
+            if *s > tiny {
+                columns.push((0..n).map(|k| u[k * n + *j] / *s).collect());
+            }
+        }
+        let mut e = 0;
+        while columns.len() < n && e < n {
+            // gram-schmidt on the standard basis, at least n - rank of them are far enough from the span
+            let mut w: Vec<T> = (0..n)
+                .map(|k| if k == e { T::one() } else { T::zero() })
+                .collect();
+            for c in &columns {
+                let d = c[e];
+                for k in 0..n {
+                    w[k] = w[k] - d * c[k];
+                }
+            }
+            let len = w.iter().fold(T::zero(), |acc, x| acc + *x * *x).sqrt();
+            if len > T::from(0.5).unwrap() {
+                columns.push(w.iter().map(|x| *x / len).collect());
+            }
+            e += 1;
+        }
+
+        Self {
+            _n: n,
+            _u: (0..n * n).map(|k| columns[k % n][k / n]).collect(),
+            _sigma: sigma,
+            _v: v,
+            _matrix: PhantomData,
+        }
+    }
+
+    pub fn u(&self) -> M {
+        M::from_func(|i, j| self._u[i * self._n + j])
+    }
+
+    // Σ as a diagonal matrix
+    pub fn sigma(&self) -> M {
+        M::from_func(|i, j| if i == j { self._sigma[i] } else { T::zero() })
+    }
+
+    pub fn singular_values(&self) -> M::VecIn {
+        M::VecIn::from_slice(&self._sigma).unwrap()
+    }
+
+    pub fn v_t(&self) -> M {
+        M::from_func(|i, j| self._v[j * self._n + i])
+    }
+
+    // singular values at or below this are rounding noise, max(σ) * n * epsilon
+    pub fn default_tolerance(&self) -> T {
+        let largest = self._sigma.first().copied().unwrap_or(T::zero());
+        largest * T::from(self._n).unwrap() * T::epsilon()
+    }
+
+    pub fn rank(&self, tolerance: T) -> usize {
+        self._sigma.iter().filter(|s| **s > tolerance).count()
+    }
+
+    // Moore-Penrose pseudo-inverse, V * Σ^+ * U^T where singular values under the tolerance count as zero
+    pub fn pseudo_inverse(&self, tolerance: T) -> M {
+        let n = self._n;
+        M::from_func(|i, j| {
+            (0..n).fold(T::zero(), |acc, k| {
+                let s = self._sigma[k];
+                if s > tolerance {
+                    acc + self._v[i * n + k] * self._u[j * n + k] / s
+                } else {
+                    acc
+                }
+            })
+        })
+    }
+
+    // U * V^T, the orthogonal matrix closest to A in the frobenius norm, may be a reflection
+    pub fn nearest_orthogonal(&self) -> M {
+        self.orthogonal_product(false)
+    }
+
+    // like nearest_orthogonal but always has determinant +1, the last axis is flipped for reflections
+    pub fn nearest_rotation(&self) -> M {
+        let flip = self.u().determinant() * self.v_t().determinant() < T::zero();
+        self.orthogonal_product(flip)
+    }
+
+    // max(σ) / min(σ) in the 2-norm, infinite for singular matrices
+    pub fn condition_number(&self) -> T {
+        match (self._sigma.first(), self._sigma.last()) {
+            (Some(&largest), Some(&smallest)) if smallest > T::zero() => largest / smallest,
+            (Some(_), Some(_)) => T::infinity(),
+            _ => T::one(),
+        }
+    }
+
+    fn orthogonal_product(&self, flip_last: bool) -> M {
+        let n = self._n;
+        M::from_func(|i, j| {
+            (0..n).fold(T::zero(), |acc, k| {
+                let val = self._u[i * n + k] * self._v[j * n + k];
+                if flip_last && k == n - 1 {
+                    acc - val
+                } else {
+                    acc + val
+                }
+            })
+        })
+    }
+}
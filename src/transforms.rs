@@ -0,0 +1,92 @@
+use crate::matrices::{Matrix4x4, MatrixOps, SquareMatrix};
+use crate::quaternion::Quaternion;
+use crate::types::Scalar;
+use crate::vectors::{Vector3D, Vector4D};
+use num_traits::Float;
+
+// affine transforms for column vectors, so they compose right to left: (t * r) * p rotates first
+impl<T: Scalar> Matrix4x4<T> {
+    pub fn translation(offset: &Vector3D<T>) -> Self {
+        let mut m = Self::identity();
+        m[(0, 3)] = offset.x;
+        m[(1, 3)] = offset.y;
+        m[(2, 3)] = offset.z;
+        m
+    }
+
+    pub fn scaling(factors: &Vector3D<T>) -> Self {
+        let mut m = Self::identity();
+        m[(0, 0)] = factors.x;
+        m[(1, 1)] = factors.y;
+        m[(2, 2)] = factors.z;
+        m
+    }
+
+    // xy is how much x moves per unit of y and so on
+    pub fn shear(xy: T, xz: T, yx: T, yz: T, zx: T, zy: T) -> Self {
+        let one = T::one();
+        let zero = T::default();
+        Self::new([
+            [one, xy, xz, zero],
+            [yx, one, yz, zero],
+            [zx, zy, one, zero],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    // treats p as a point (w = 1), so translation applies, and divides by the resulting w.
+    // None when w comes out zero, the point went off to infinity
+    pub fn transform_point(&self, p: &Vector3D<T>) -> Option<Vector3D<T>> {
+        let v = self.mul_vec(&Vector4D::new(p.x, p.y, p.z, T::one()));
+        if v.w == T::default() {
+            return None;
+        }
+        if v.w == T::one() {
+            return Some(Vector3D::new(v.x, v.y, v.z));
+        }
+        Some(Vector3D::new(v.x / v.w, v.y / v.w, v.z / v.w))
+    }
+
+    // treats v as a direction (w = 0), so translation is ignored
+    pub fn transform_vector(&self, v: &Vector3D<T>) -> Vector3D<T> {
+        let r = self.mul_vec(&Vector4D::new(v.x, v.y, v.z, T::default()));
+        Vector3D::new(r.x, r.y, r.z)
+    }
+}
+
+impl<T: Scalar + Float> Matrix4x4<T> {
+    pub fn rotation_x(angle: T) -> Self {
+        let (s, c) = angle.sin_cos();
+        let mut m = Self::identity();
+        m[(1, 1)] = c;
+        m[(1, 2)] = -s;
+        m[(2, 1)] = s;
+        m[(2, 2)] = c;
+        m
+    }
+
+    pub fn rotation_y(angle: T) -> Self {
+        let (s, c) = angle.sin_cos();
+        let mut m = Self::identity();
+        m[(0, 0)] = c;
+        m[(0, 2)] = s;
+        m[(2, 0)] = -s;
+        m[(2, 2)] = c;
+        m
+    }
+
+    pub fn rotation_z(angle: T) -> Self {
+        let (s, c) = angle.sin_cos();
+        let mut m = Self::identity();
+        m[(0, 0)] = c;
+        m[(0, 1)] = -s;
+        m[(1, 0)] = s;
+        m[(1, 1)] = c;
+        m
+    }
+
+    // right-handed rotation around an arbitrary axis, a zero axis gives the identity
+    pub fn rotation_axis(axis: &Vector3D<T>, angle: T) -> Self {
+        Quaternion::from_axis_angle(axis, angle).to_matrix4x4()
+    }
+}
@@ -1,11 +1,224 @@
-use num_traits::{One, Signed, ToPrimitive};
+use num_traits::{Num, ToPrimitive, Zero};
+use std::ops::Neg;
 
-pub trait Scalar: Signed + ToPrimitive + PartialOrd + Default + Copy + One {}
+// what vectors and matrices are made of, nothing here needs an ordering or a sign,
+// so complex numbers and unsigned integers fit too
+pub trait Scalar: Num + ToPrimitive + Default + Copy {
+    // what norms and tolerances are measured in, Self for the real types and F for Complex<F>
+    type Real: Scalar<Real = Self::Real> + PartialOrd;
 
-impl Scalar for f32 {}
-impl Scalar for f64 {}
-impl Scalar for i8 {}
-impl Scalar for i16 {}
-impl Scalar for i32 {}
-impl Scalar for i64 {}
-impl Scalar for i128 {}
+    // true when division is exact (a / b * b == a), elimination based algorithms rely on it
+    const IS_FIELD: bool = false;
+
+    // true when division truncates to a whole number, so a fraction can't be represented at all
+    const IS_INTEGER: bool = false;
+
+    // |x|, the absolute value for real types and the modulus for complex ones
+    fn norm(self) -> Self::Real;
+
+    // complex conjugate, real types are their own conjugate
+    fn conj(self) -> Self {
+        self
+    }
+
+    // relative rounding error of one operation, zero for exact types
+    fn machine_epsilon() -> Self::Real {
+        Self::Real::zero()
+    }
+
+    // None when the result doesn't fit, floats never overflow here (they go to infinity instead)
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    // also None for division by zero on types that would panic on it
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        Some(self / rhs)
+    }
+
+    // |self - other|, unsigned types can't go through self - other when other is bigger
+    fn distance(self, other: Self) -> Self::Real {
+        (self - other).norm()
+    }
+
+    // false for NaN and the infinities
+    fn is_finite(self) -> bool {
+        true
+    }
+}
+
+// scalars with a negation, inverting and solving need it so unsigned types are left out there
+pub trait SignedScalar: Scalar + Neg<Output = Self> {}
+
+impl<T: Scalar + Neg<Output = T>> SignedScalar for T {}
+
+// scalars that can take their own square root, so lengths don't have to leave the type
+pub trait Sqrt: Scalar {
+    fn sqrt(self) -> Self;
+}
+
+// real numbers with the float functions geometry needs, so lengths, directions and angles come
+// out in T itself. is_finite comes from Scalar, the fixed-point types stop at Sqrt
+pub trait RealScalar: Sqrt + SignedScalar + Scalar<Real = Self> + PartialOrd {
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    // angle of the point (other, self), in (-pi, pi]
+    fn atan2(self, other: Self) -> Self;
+
+    // gap between 1 and the next representable number
+    fn epsilon() -> Self;
+}
+
+macro_rules! impl_float_scalar {
+    ($t:ty) => {
+        impl Scalar for $t {
+            type Real = $t;
+
+            const IS_FIELD: bool = true;
+
+            fn norm(self) -> Self {
+                self.abs()
+            }
+
+            fn machine_epsilon() -> Self {
+                <$t>::EPSILON
+            }
+
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+        }
+
+        impl Sqrt for $t {
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+        }
+
+        impl RealScalar for $t {
+            fn sin(self) -> Self {
+                <$t>::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                <$t>::cos(self)
+            }
+
+            fn tan(self) -> Self {
+                <$t>::tan(self)
+            }
+
+            fn asin(self) -> Self {
+                <$t>::asin(self)
+            }
+
+            fn acos(self) -> Self {
+                <$t>::acos(self)
+            }
+
+            fn atan(self) -> Self {
+                <$t>::atan(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                <$t>::atan2(self, other)
+            }
+
+            fn epsilon() -> Self {
+                <$t>::EPSILON
+            }
+        }
+    };
+}
+
+macro_rules! impl_int_scalar {
+    ($t:ty) => {
+        impl Scalar for $t {
+            type Real = $t;
+
+            const IS_INTEGER: bool = true;
+
+            fn norm(self) -> Self {
+                self.abs()
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_div(self, rhs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_uint_scalar {
+    ($t:ty) => {
+        impl Scalar for $t {
+            type Real = $t;
+
+            const IS_INTEGER: bool = true;
+
+            fn norm(self) -> Self {
+                self
+            }
+
+            fn distance(self, other: Self) -> Self {
+                self.abs_diff(other)
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_div(self, rhs)
+            }
+        }
+    };
+}
+
+impl_float_scalar!(f32);
+impl_float_scalar!(f64);
+
+impl_int_scalar!(i8);
+impl_int_scalar!(i16);
+impl_int_scalar!(i32);
+impl_int_scalar!(i64);
+impl_int_scalar!(i128);
+
+impl_uint_scalar!(u8);
+impl_uint_scalar!(u16);
+impl_uint_scalar!(u32);
+impl_uint_scalar!(u64);
+impl_uint_scalar!(u128);
+impl_uint_scalar!(usize);
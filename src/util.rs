@@ -1,18 +1,181 @@
-use num_traits::Float;
+use crate::types::{RealScalar, Scalar};
+use num_traits::{Float, One, PrimInt, Zero};
 
-pub fn normalize_float_arr<F: Float, const N: usize>(arr: &mut [F; N]) {
+// cap on the jacobi sweeps in eigen and svd. both converge quadratically, real matrices are done
+// well before this
+pub const MAX_SWEEPS: usize = 64;
+
+pub fn scale_to_max_abs_arr<F: Float, const N: usize>(arr: &mut [F; N]) {
     let max = find_max_abs_arr(arr);
+    // the zero vector stays zero instead of turning into NaNs
+    if max == F::zero() {
+        return;
+    }
     for e in arr {
         *e = *e / max;
     }
 }
 
+// every component goes to f64 on its own, where even u128::MAX fits, and is scaled there. the
+// f32 versions narrow afterwards so they can't overflow
+pub fn scaled_to_max_abs<T: PrimInt, const N: usize>(arr: [T; N]) -> [f64; N] {
+    let mut vals = arr.map(to_f64);
+    scale_to_max_abs_arr(&mut vals);
+    vals
+}
+
+// length of an integer vector. dividing by the largest component first keeps the squares from
+// overflowing, in the integer type and in f64 alike
+pub fn f64_magnitude<T: PrimInt>(data: &[T]) -> f64 {
+    let vals: Vec<f64> = data.iter().copied().map(to_f64).collect();
+    let max = find_max_abs_arr(&vals);
+    if max == 0. || !max.is_finite() {
+        return max;
+    }
+    vals.iter()
+        .map(|v| (v / max) * (v / max))
+        .sum::<f64>()
+        .sqrt()
+        * max
+}
+
+// a primitive integer always converts, at worst with rounding
+fn to_f64<T: PrimInt>(v: T) -> f64 {
+    v.to_f64().unwrap()
+}
+
+// unit length copy of data, None if the length is not above epsilon or isn't finite. dividing by
+// the largest component before squaring keeps big inputs from overflowing, like f64_magnitude
+pub fn normalize_slice<T: RealScalar>(data: &[T], epsilon: T) -> Option<Vec<T>> {
+    let mut max = T::zero();
+    for v in data {
+        let v = v.norm();
+        if !v.is_finite() {
+            return None;
+        }
+        if v > max {
+            max = v;
+        }
+    }
+    if max.is_zero() {
+        return None;
+    }
+    let scaled: Vec<T> = data.iter().map(|v| *v / max).collect();
+    let len = scaled.iter().fold(T::zero(), |acc, v| acc + *v * *v).sqrt();
+    if max * len <= epsilon {
+        return None;
+    }
+    Some(scaled.iter().map(|v| *v / len).collect())
+}
+
+// a NaN component wins, so it carries through to the result instead of being skipped
 fn find_max_abs_arr<F: Float>(arr: &[F]) -> F {
-    let mut max = F::min_value();
+    let mut max = F::zero();
     arr.iter().map(|v| v.abs()).for_each(|v| {
-        if v > max {
+        if v > max || v.is_nan() {
             max = v;
         }
     });
     max
 }
+
+// bareiss keeps integer types exact, but it only pivots away from exact zeros, so a tiny float
+// pivot would swamp the rest. the field types eliminate with partial pivoting like LU instead
+pub fn determinant<T: Scalar>(data: &mut [T], n: usize) -> Option<T> {
+    if T::IS_FIELD {
+        determinant_pivoted(data, n)
+    } else {
+        determinant_bareiss(data, n)
+    }
+}
+
+// gaussian elimination on the largest pivot of each column, None if an intermediate overflows
+pub fn determinant_pivoted<T: Scalar>(data: &mut [T], n: usize) -> Option<T> {
+    let mut negate = false;
+    let mut det = T::one();
+    for k in 0..n {
+        let mut pivot = k;
+        for r in k + 1..n {
+            if data[r * n + k].norm() > data[pivot * n + k].norm() {
+                pivot = r;
+            }
+        }
+        if pivot != k {
+            for c in 0..n {
+                data.swap(k * n + c, pivot * n + c);
+            }
+            negate = !negate;
+        }
+
+        let p = data[k * n + k];
+        if p == T::default() {
+            return Some(T::default());
+        }
+        det = det.checked_mul(p)?;
+        for i in k + 1..n {
+            let factor = data[i * n + k].checked_div(p)?;
+            for j in k + 1..n {
+                let d = factor.checked_mul(data[k * n + j])?;
+                data[i * n + j] = data[i * n + j].checked_sub(d)?;
+            }
+        }
+    }
+
+    if negate {
+        T::default().checked_sub(det)
+    } else {
+        Some(det)
+    }
+}
+
+// fraction-free gaussian elimination, exact for integer scalars too, None if an intermediate overflows
+pub fn determinant_bareiss<T: Scalar>(data: &mut [T], n: usize) -> Option<T> {
+    if n == 0 {
+        return Some(T::one());
+    }
+
+    let mut negate = false;
+    let mut prev = T::one();
+    for k in 0..n - 1 {
+        if data[k * n + k] == T::default() {
+            let Some(pivot) = (k + 1..n).find(|&r| data[r * n + k] != T::default()) else {
+                return Some(T::default());
+            };
+            for c in 0..n {
+                data.swap(k * n + c, pivot * n + c);
+            }
+            negate = !negate;
+        }
+
+        for i in k + 1..n {
+            for j in k + 1..n {
+                let a = data[i * n + j].checked_mul(data[k * n + k])?;
+                let b = data[i * n + k].checked_mul(data[k * n + j])?;
+                data[i * n + j] = a.checked_sub(b)?.checked_div(prev)?;
+            }
+        }
+        prev = data[k * n + k];
+    }
+
+    // 0 - det rather than -det, an odd number of swaps has no answer for unsigned types
+    let det = data[n * n - 1];
+    if negate {
+        T::default().checked_sub(det)
+    } else {
+        Some(det)
+    }
+}
+
+// largest * n * epsilon, anything at or below it is rounding noise. zero for the exact types
+pub fn rounding_tolerance<T: Scalar>(largest: T::Real, n: usize) -> T::Real {
+    let size = (0..n).fold(T::Real::zero(), |acc, _| acc + T::Real::one());
+    largest * size * T::machine_epsilon()
+}
+
+// largest |x| of any entry, what pivot tolerances are scaled by
+pub fn max_norm<T: Scalar>(data: &[T]) -> T::Real {
+    data.iter().fold(T::Real::zero(), |acc, v| {
+        let v = v.norm();
+        if v > acc { v } else { acc }
+    })
+}
@@ -1,6 +1,10 @@
 use crate::errors::VectorErr;
-use crate::types::Scalar;
-use crate::util::normalize_float_arr;
+use crate::types::{RealScalar, Scalar, SignedScalar, Sqrt};
+use crate::util::{f64_magnitude, normalize_slice, scaled_to_max_abs};
+use num_traits::PrimInt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 // vector trait for both 2D and 3D
 pub trait Vector<T: Scalar>: Sized {
@@ -9,262 +13,329 @@ pub trait Vector<T: Scalar>: Sized {
 
     fn zero() -> Self;
     fn from_slice(data: &[T]) -> Result<Self, VectorErr>;
+    #[allow(clippy::ptr_arg)]
     fn from_vec(data: &Vec<T>) -> Result<Self, VectorErr>;
-    fn invert(&self) -> Self;
-    fn normalize_f32(&self) -> Self::VecF32;
-    fn normalize_f64(&self) -> Self::VecF64;
-    fn precision_eq(&self, other: &Self, precision: T) -> bool;
+    fn to_vec(&self) -> Vec<T>;
+    fn invert(&self) -> Self
+    where
+        T: SignedScalar;
+    // divides by the largest absolute component, so it lands in [-1, 1] but is NOT unit length
+    fn scale_to_max_abs_f32(&self) -> Self::VecF32
+    where
+        T: PrimInt;
+    fn scale_to_max_abs_f64(&self) -> Self::VecF64
+    where
+        T: PrimInt;
+    fn precision_eq(&self, other: &Self, precision: T::Real) -> bool;
     fn add(&self, other: &Self) -> Self;
     fn sub(&self, other: &Self) -> Self;
     fn dot(&self, other: &Self) -> T;
     fn scale(&self, a: T) -> Self;
-    fn magnitude_f32(&self) -> f32;
-    fn magnitude_f64(&self) -> f64;
+
+    // length in the scalar type itself, nothing goes through a float for the fixed-point types
+    fn magnitude(&self) -> T
+    where
+        T: Sqrt,
+    {
+        self.dot(self).sqrt()
+    }
+
+    // unit length vector, None for the zero vector
+    fn normalized(&self) -> Option<Self>
+    where
+        T: RealScalar,
+    {
+        self.try_normalize(T::zero())
+    }
+
+    // None if the length is not above epsilon, as dividing by it would blow up
+    fn try_normalize(&self, epsilon: T) -> Option<Self>
+    where
+        T: RealScalar,
+    {
+        Self::from_vec(&normalize_slice(&self.to_vec(), epsilon)?).ok()
+    }
+
+    // integer vectors only, the float ones have magnitude()
+    fn magnitude_f64(&self) -> f64
+    where
+        T: PrimInt,
+    {
+        f64_magnitude(&self.to_vec())
+    }
+
+    fn magnitude_f32(&self) -> f32
+    where
+        T: PrimInt,
+    {
+        self.magnitude_f64() as f32
+    }
+
+    // scaling down by the largest component first keeps big integers from overflowing the f32
+    fn normalized_f32(&self) -> Option<Self::VecF32>
+    where
+        T: PrimInt,
+    {
+        self.scale_to_max_abs_f32().normalized()
+    }
+
+    fn normalized_f64(&self) -> Option<Self::VecF64>
+    where
+        T: PrimInt,
+    {
+        self.scale_to_max_abs_f64().normalized()
+    }
 }
 
 #[derive(PartialEq, Clone, Debug)]
-pub struct Vector2D<T> {
-    pub x: T,
-    pub y: T,
+pub struct VectorN<T, const N: usize> {
+    pub data: [T; N],
+}
+
+impl<T, const N: usize> VectorN<T, N> {
+    pub fn new(data: [T; N]) -> Self {
+        Self { data }
+    }
 }
 
-impl<T> Vector2D<T> {
-    pub fn new(x: T, y: T) -> Self {
-        Self { x, y }
+impl<T: Scalar, const N: usize> VectorN<T, N> {
+    pub fn from_func(f: impl Fn(usize) -> T) -> Self {
+        Self {
+            data: std::array::from_fn(f),
+        }
     }
 }
 
-impl<T: Scalar> Vector<T> for Vector2D<T> {
-    type VecF32 = Vector2D<f32>;
-    type VecF64 = Vector2D<f64>;
+impl<T: Scalar, const N: usize> Vector<T> for VectorN<T, N> {
+    type VecF32 = VectorN<f32, N>;
+    type VecF64 = VectorN<f64, N>;
 
     fn zero() -> Self {
         Self {
-            x: T::default(),
-            y: T::default(),
+            data: [T::default(); N],
         }
     }
 
-    fn from_slice(data: &[T]) -> Result<Self, VectorErr>
-    where
-        Self: Sized,
-    {
-        if data.len() != 2 {
+    fn from_slice(data: &[T]) -> Result<Self, VectorErr> {
+        if data.len() != N {
             return Err(VectorErr::LengthErr {
-                expected: 2,
+                expected: N,
                 got: data.len(),
             });
         }
 
-        Ok(Self {
-            x: data[0],
-            y: data[1],
-        })
+        Ok(Self::from_func(|i| data[i]))
     }
 
-    fn from_vec(data: &Vec<T>) -> Result<Self, VectorErr>
-    where
-        Self: Sized,
-    {
+    fn from_vec(data: &Vec<T>) -> Result<Self, VectorErr> {
         Self::from_slice(data.as_slice())
     }
 
-    fn invert(&self) -> Self {
-        Self {
-            x: -self.x,
-            y: -self.y,
-        }
+    fn to_vec(&self) -> Vec<T> {
+        self.data.to_vec()
     }
 
-    fn normalize_f32(&self) -> Self::VecF32 {
-        let mut vals = [self.x.to_f32().unwrap(), self.y.to_f32().unwrap()];
-        normalize_float_arr(&mut vals);
-        let [x, y] = vals;
-        Self::VecF32::new(x, y)
+    fn invert(&self) -> Self
+    where
+        T: SignedScalar,
+    {
+        Self::from_func(|i| -self.data[i])
+    }
+
+    fn scale_to_max_abs_f32(&self) -> Self::VecF32
+    where
+        T: PrimInt,
+    {
+        VectorN::new(scaled_to_max_abs(self.data).map(|v| v as f32))
     }
 
-    fn normalize_f64(&self) -> Self::VecF64 {
-        let mut vals = [self.x.to_f64().unwrap(), self.y.to_f64().unwrap()];
-        normalize_float_arr(&mut vals);
-        let [x, y] = vals;
-        Self::VecF64::new(x, y)
+    fn scale_to_max_abs_f64(&self) -> Self::VecF64
+    where
+        T: PrimInt,
+    {
+        VectorN::new(scaled_to_max_abs(self.data))
     }
 
-    fn precision_eq(&self, other: &Self, precision: T) -> bool {
-        let cmp_vals = [(self.x, other.x), (self.y, other.y)];
-        cmp_vals.iter().all(|(s, o)| (*s - *o).abs() <= precision)
+    fn precision_eq(&self, other: &Self, precision: T::Real) -> bool {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(s, o)| s.distance(*o) <= precision)
     }
 
     fn add(&self, other: &Self) -> Self {
-        Self {
-            x: self.x + other.x,
-            y: self.y + other.y,
-        }
+        Self::from_func(|i| self.data[i] + other.data[i])
     }
 
     fn sub(&self, other: &Self) -> Self {
-        Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
+        Self::from_func(|i| self.data[i] - other.data[i])
     }
 
     fn dot(&self, other: &Self) -> T {
-        self.x * other.x + self.y * other.y
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .fold(T::default(), |acc, (s, o)| acc + *s * *o)
     }
 
     fn scale(&self, a: T) -> Self {
-        Self {
-            x: self.x * a,
-            y: self.y * a,
-        }
+        Self::from_func(|i| self.data[i] * a)
     }
+}
+
+impl<T, const N: usize> Index<usize> for VectorN<T, N> {
+    type Output = T;
 
-    fn magnitude_f32(&self) -> f32 {
-        (self.x * self.x + self.y * self.y).to_f32().unwrap().sqrt()
+    fn index(&self, i: usize) -> &T {
+        &self.data[i]
     }
+}
 
-    fn magnitude_f64(&self) -> f64 {
-        (self.x * self.x + self.y * self.y).to_f64().unwrap().sqrt()
+impl<T, const N: usize> IndexMut<usize> for VectorN<T, N> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.data[i]
     }
 }
 
-#[derive(PartialEq, Clone, Debug)]
-pub struct Vector3D<T> {
-    pub x: T,
-    pub y: T,
-    pub z: T,
+impl<T: Scalar, const N: usize> Add for VectorN<T, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Vector::add(&self, &rhs)
+    }
 }
 
-impl<T: Scalar> Vector3D<T> {
-    pub fn new(x: T, y: T, z: T) -> Self {
-        Self { x, y, z }
+impl<T: Scalar, const N: usize> Add for &VectorN<T, N> {
+    type Output = VectorN<T, N>;
+
+    fn add(self, rhs: Self) -> VectorN<T, N> {
+        Vector::add(self, rhs)
     }
+}
 
-    pub fn cross(&self, other: &Self) -> Self {
-        Vector3D::new(
-            self.y * other.z - self.z * other.y,
-            self.z * other.x - self.x * other.z,
-            self.x * other.y - self.y * other.x,
-        )
+impl<T: Scalar, const N: usize> Sub for VectorN<T, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Vector::sub(&self, &rhs)
     }
 }
 
-impl<T: Scalar> Vector<T> for Vector3D<T> {
-    type VecF32 = Vector3D<f32>;
-    type VecF64 = Vector3D<f64>;
+impl<T: Scalar, const N: usize> Sub for &VectorN<T, N> {
+    type Output = VectorN<T, N>;
 
-    fn zero() -> Self {
-        Self {
-            x: T::default(),
-            y: T::default(),
-            z: T::default(),
-        }
+    fn sub(self, rhs: Self) -> VectorN<T, N> {
+        Vector::sub(self, rhs)
     }
+}
 
-    fn from_slice(data: &[T]) -> Result<Self, VectorErr>
-    where
-        Self: Sized,
-    {
-        if data.len() != 3 {
-            return Err(VectorErr::LengthErr {
-                expected: 3,
-                got: data.len(),
-            });
-        }
+impl<T: SignedScalar, const N: usize> Neg for VectorN<T, N> {
+    type Output = Self;
 
-        Ok(Self {
-            x: data[0],
-            y: data[1],
-            z: data[2],
-        })
+    fn neg(self) -> Self {
+        self.invert()
     }
+}
 
-    fn from_vec(data: &Vec<T>) -> Result<Self, VectorErr>
-    where
-        Self: Sized,
-    {
-        Self::from_slice(data.as_slice())
+impl<T: SignedScalar, const N: usize> Neg for &VectorN<T, N> {
+    type Output = VectorN<T, N>;
+
+    fn neg(self) -> VectorN<T, N> {
+        self.invert()
     }
+}
 
-    fn invert(&self) -> Self {
-        Self {
-            x: -self.x,
-            y: -self.y,
-            z: -self.z,
-        }
+impl<T: Scalar, const N: usize> Mul<T> for VectorN<T, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        self.scale(rhs)
     }
+}
 
-    fn normalize_f32(&self) -> Self::VecF32 {
-        let mut vals = [
-            self.x.to_f32().unwrap(),
-            self.y.to_f32().unwrap(),
-            self.z.to_f32().unwrap(),
-        ];
-        normalize_float_arr(&mut vals);
-        let [x, y, z] = vals;
-        Self::VecF32::new(x, y, z)
+impl<T: Scalar, const N: usize> Mul<T> for &VectorN<T, N> {
+    type Output = VectorN<T, N>;
+
+    fn mul(self, rhs: T) -> VectorN<T, N> {
+        self.scale(rhs)
     }
+}
+
+impl<T: Scalar, const N: usize> Div<T> for VectorN<T, N> {
+    type Output = Self;
 
-    fn normalize_f64(&self) -> Self::VecF64 {
-        let mut vals = [
-            self.x.to_f64().unwrap(),
-            self.y.to_f64().unwrap(),
-            self.z.to_f64().unwrap(),
-        ];
-        normalize_float_arr(&mut vals);
-        let [x, y, z] = vals;
-        Self::VecF64::new(x, y, z)
+    fn div(self, rhs: T) -> Self {
+        Self::from_func(|i| self.data[i] / rhs)
     }
+}
+
+impl<T: Scalar, const N: usize> Div<T> for &VectorN<T, N> {
+    type Output = VectorN<T, N>;
 
-    fn precision_eq(&self, other: &Self, precision: T) -> bool {
-        let cmp_vals = [(self.x, other.x), (self.y, other.y), (self.z, other.z)];
-        cmp_vals.iter().all(|(s, o)| (*s - *o).abs() <= precision)
+    fn div(self, rhs: T) -> VectorN<T, N> {
+        VectorN::from_func(|i| self.data[i] / rhs)
     }
+}
 
-    fn add(&self, other: &Self) -> Self {
-        Self {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-        }
+impl<T: Scalar, const N: usize> AddAssign for VectorN<T, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
     }
+}
 
-    fn sub(&self, other: &Self) -> Self {
-        Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
+impl<T: Scalar, const N: usize> AddAssign<&VectorN<T, N>> for VectorN<T, N> {
+    fn add_assign(&mut self, rhs: &Self) {
+        for (s, o) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *s = *s + *o;
         }
     }
+}
 
-    fn dot(&self, other: &Self) -> T {
-        self.x * other.x + self.y * other.y + self.z * other.z
+impl<T: Scalar, const N: usize> SubAssign for VectorN<T, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
     }
+}
 
-    fn scale(&self, a: T) -> Self {
-        Self {
-            x: self.x * a,
-            y: self.y * a,
-            z: self.z * a,
+impl<T: Scalar, const N: usize> SubAssign<&VectorN<T, N>> for VectorN<T, N> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        for (s, o) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *s = *s - *o;
         }
     }
+}
 
-    fn magnitude_f32(&self) -> f32 {
-        (self.x * self.x + self.y * self.y + self.z * self.z)
-            .to_f32()
-            .unwrap()
-            .sqrt()
+impl<T: Scalar, const N: usize> MulAssign<T> for VectorN<T, N> {
+    fn mul_assign(&mut self, rhs: T) {
+        for s in self.data.iter_mut() {
+            *s = *s * rhs;
+        }
     }
+}
 
-    fn magnitude_f64(&self) -> f64 {
-        (self.x * self.x + self.y * self.y + self.z * self.z)
-            .to_f64()
-            .unwrap()
-            .sqrt()
+impl<T: Scalar, const N: usize> DivAssign<T> for VectorN<T, N> {
+    fn div_assign(&mut self, rhs: T) {
+        for s in self.data.iter_mut() {
+            *s = *s / rhs;
+        }
     }
 }
 
+// the named sizes are thin wrappers over VectorN that keep the x, y, z, w fields, every operation
+// goes through VectorN so there is only one implementation of the arithmetic
+#[derive(PartialEq, Clone, Debug)]
+pub struct Vector2D<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct Vector3D<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
 #[derive(PartialEq, Clone, Debug)]
 pub struct Vector4D<T> {
     pub x: T,
@@ -273,136 +344,223 @@ pub struct Vector4D<T> {
     pub w: T,
 }
 
-impl<T> Vector4D<T> {
-    pub fn new(x: T, y: T, z: T, w: T) -> Self {
-        Self { x, y, z, w }
+impl<T: Scalar> Vector3D<T> {
+    pub fn cross(&self, other: &Self) -> Self {
+        Vector3D::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
     }
 }
 
-impl<T: Scalar> Vector<T> for Vector4D<T> {
-    type VecF32 = Vector4D<f32>;
-    type VecF64 = Vector4D<f64>;
+macro_rules! impl_named_vector {
+    ($vec:ident { $($field:ident),+ }, $n:literal) => {
+        impl<T> $vec<T> {
+            pub fn new($($field: T),+) -> Self {
+                Self { $($field),+ }
+            }
+        }
 
-    fn zero() -> Self {
-        Self {
-            x: T::default(),
-            y: T::default(),
-            z: T::default(),
-            w: T::default(),
+        impl<T: Copy> $vec<T> {
+            fn inner(&self) -> VectorN<T, $n> {
+                VectorN::new([$(self.$field),+])
+            }
         }
-    }
 
-    fn from_slice(data: &[T]) -> Result<Self, VectorErr>
-    where
-        Self: Sized,
-    {
-        if data.len() != 4 {
-            return Err(VectorErr::LengthErr {
-                expected: 4,
-                got: data.len(),
-            });
+        impl<T> From<$vec<T>> for VectorN<T, $n> {
+            fn from(v: $vec<T>) -> Self {
+                VectorN::new([$(v.$field),+])
+            }
         }
 
-        Ok(Self {
-            x: data[0],
-            y: data[1],
-            z: data[2],
-            w: data[3],
-        })
-    }
+        impl<T> From<VectorN<T, $n>> for $vec<T> {
+            fn from(v: VectorN<T, $n>) -> Self {
+                let [$($field),+] = v.data;
+                $vec { $($field),+ }
+            }
+        }
 
-    fn from_vec(data: &Vec<T>) -> Result<Self, VectorErr>
-    where
-        Self: Sized,
-    {
-        Self::from_slice(data.as_slice())
-    }
+        impl<T: Scalar> Vector<T> for $vec<T> {
+            type VecF32 = $vec<f32>;
+            type VecF64 = $vec<f64>;
+
+            fn zero() -> Self {
+                VectorN::zero().into()
+            }
+
+            fn from_slice(data: &[T]) -> Result<Self, VectorErr> {
+                VectorN::from_slice(data).map(Self::from)
+            }
+
+            fn from_vec(data: &Vec<T>) -> Result<Self, VectorErr> {
+                Self::from_slice(data.as_slice())
+            }
+
+            fn to_vec(&self) -> Vec<T> {
+                vec![$(self.$field),+]
+            }
+
+            fn invert(&self) -> Self
+            where
+                T: SignedScalar,
+            {
+                self.inner().invert().into()
+            }
+
+            fn scale_to_max_abs_f32(&self) -> Self::VecF32
+            where
+                T: PrimInt,
+            {
+                self.inner().scale_to_max_abs_f32().into()
+            }
+
+            fn scale_to_max_abs_f64(&self) -> Self::VecF64
+            where
+                T: PrimInt,
+            {
+                self.inner().scale_to_max_abs_f64().into()
+            }
+
+            fn precision_eq(&self, other: &Self, precision: T::Real) -> bool {
+                self.inner().precision_eq(&other.inner(), precision)
+            }
+
+            fn add(&self, other: &Self) -> Self {
+                Vector::add(&self.inner(), &other.inner()).into()
+            }
+
+            fn sub(&self, other: &Self) -> Self {
+                Vector::sub(&self.inner(), &other.inner()).into()
+            }
+
+            fn dot(&self, other: &Self) -> T {
+                self.inner().dot(&other.inner())
+            }
+
+            fn scale(&self, a: T) -> Self {
+                self.inner().scale(a).into()
+            }
+        }
 
-    fn invert(&self) -> Self {
-        Self {
-            x: -self.x,
-            y: -self.y,
-            z: -self.z,
-            w: -self.w,
+        impl<T: Scalar> Add for $vec<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Vector::add(&self, &rhs)
+            }
         }
-    }
 
-    fn normalize_f32(&self) -> Self::VecF32 {
-        let mut vals = [
-            self.x.to_f32().unwrap(),
-            self.y.to_f32().unwrap(),
-            self.z.to_f32().unwrap(),
-            self.w.to_f32().unwrap(),
-        ];
-        normalize_float_arr(&mut vals);
-        let [x, y, z, w] = vals;
-        Self::VecF32::new(x, y, z, w)
-    }
-
-    fn normalize_f64(&self) -> Self::VecF64 {
-        let mut vals = [
-            self.x.to_f64().unwrap(),
-            self.y.to_f64().unwrap(),
-            self.z.to_f64().unwrap(),
-            self.w.to_f64().unwrap(),
-        ];
-        normalize_float_arr(&mut vals);
-        let [x, y, z, w] = vals;
-        Self::VecF64::new(x, y, z, w)
-    }
-
-    fn precision_eq(&self, other: &Self, precision: T) -> bool {
-        let cmp_vals = [
-            (self.x, other.x),
-            (self.y, other.y),
-            (self.z, other.z),
-            (self.w, other.w),
-        ];
-        cmp_vals.iter().all(|(s, o)| (*s - *o).abs() <= precision)
-    }
+        impl<T: Scalar> Add for &$vec<T> {
+            type Output = $vec<T>;
 
-    fn add(&self, other: &Self) -> Self {
-        Self {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-            w: self.w + other.w,
+            fn add(self, rhs: Self) -> $vec<T> {
+                Vector::add(self, rhs)
+            }
         }
-    }
 
-    fn sub(&self, other: &Self) -> Self {
-        Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-            w: self.w - other.w,
+        impl<T: Scalar> Sub for $vec<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Vector::sub(&self, &rhs)
+            }
         }
-    }
 
-    fn dot(&self, other: &Self) -> T {
-        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
-    }
+        impl<T: Scalar> Sub for &$vec<T> {
+            type Output = $vec<T>;
 
-    fn scale(&self, a: T) -> Self {
-        Self {
-            x: self.x * a,
-            y: self.y * a,
-            z: self.z * a,
-            w: self.w * a,
+            fn sub(self, rhs: Self) -> $vec<T> {
+                Vector::sub(self, rhs)
+            }
         }
-    }
 
-    fn magnitude_f32(&self) -> f32 {
-        (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w)
-            .to_f32()
-            .unwrap()
-            .sqrt()
-    }
+        impl<T: SignedScalar> Neg for $vec<T> {
+            type Output = Self;
 
-    fn magnitude_f64(&self) -> f64 {
-        (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w)
-            .to_f64()
-            .unwrap()
-            .sqrt()
-    }
+            fn neg(self) -> Self {
+                self.invert()
+            }
+        }
+
+        impl<T: SignedScalar> Neg for &$vec<T> {
+            type Output = $vec<T>;
+
+            fn neg(self) -> $vec<T> {
+                self.invert()
+            }
+        }
+
+        impl<T: Scalar> Mul<T> for $vec<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                self.scale(rhs)
+            }
+        }
+
+        impl<T: Scalar> Mul<T> for &$vec<T> {
+            type Output = $vec<T>;
+
+            fn mul(self, rhs: T) -> $vec<T> {
+                self.scale(rhs)
+            }
+        }
+
+        impl<T: Scalar> Div<T> for $vec<T> {
+            type Output = Self;
+
+            fn div(self, rhs: T) -> Self {
+                (self.inner() / rhs).into()
+            }
+        }
+
+        impl<T: Scalar> Div<T> for &$vec<T> {
+            type Output = $vec<T>;
+
+            fn div(self, rhs: T) -> $vec<T> {
+                (self.inner() / rhs).into()
+            }
+        }
+
+        impl<T: Scalar> AddAssign for $vec<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self += &rhs;
+            }
+        }
+
+        impl<T: Scalar> AddAssign<&$vec<T>> for $vec<T> {
+            fn add_assign(&mut self, rhs: &Self) {
+                *self = Vector::add(&*self, rhs);
+            }
+        }
+
+        impl<T: Scalar> SubAssign for $vec<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self -= &rhs;
+            }
+        }
+
+        impl<T: Scalar> SubAssign<&$vec<T>> for $vec<T> {
+            fn sub_assign(&mut self, rhs: &Self) {
+                *self = Vector::sub(&*self, rhs);
+            }
+        }
+
+        impl<T: Scalar> MulAssign<T> for $vec<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                *self = self.scale(rhs);
+            }
+        }
+
+        impl<T: Scalar> DivAssign<T> for $vec<T> {
+            fn div_assign(&mut self, rhs: T) {
+                *self = &*self / rhs;
+            }
+        }
+    };
 }
+
+impl_named_vector!(Vector2D { x, y }, 2);
+impl_named_vector!(Vector3D { x, y, z }, 3);
+impl_named_vector!(Vector4D { x, y, z, w }, 4);
@@ -0,0 +1,62 @@
+use yelmath::prelude::*;
+
+#[test]
+fn prelude_vectors() {
+    let v2 = Vector2D::new(1, 2).add(&Vector2D::new(3, 4));
+    let v3 = Vector3D::new(1, 0, 0).cross(&Vector3D::new(0, 1, 0));
+    let v4 = Vector4D::new(1, 2, 3, 4).scale(2);
+
+    assert_eq!(v2, Vector2D::new(4, 6));
+    assert_eq!(v3, Vector3D::new(0, 0, 1));
+    assert_eq!(v4, Vector4D::new(2, 4, 6, 8));
+    assert_eq!(Vector3D::new(2, -3, 6).magnitude_f64(), 7.);
+    assert_eq!(Vector3D::new(2., -3., 6.).magnitude(), 7.);
+    assert_eq!(Vector2D::new(3u32, 4).add(&Vector2D::new(1, 1)).dot(&Vector2D::new(1, 1)), 9);
+    assert_eq!(Vector2D::new(Q16_16::from_int(3), Q16_16::from_int(4)).magnitude(), Q16_16::from_int(5));
+}
+
+#[test]
+fn prelude_matrices() {
+    let m3 = Matrix3x3::new([
+        [2, -3, 1],
+        [2, 0, -1],
+        [1, 4, 5]
+    ]);
+    let m4 = Matrix4x4::<f64>::identity();
+
+    assert_eq!(m3.determinant(), 49);
+    assert_eq!(m3.mul_vec(&Vector3D::new(1, 0, 0)), Vector3D::new(2, 2, 1));
+    assert_eq!(m4.inverse().unwrap(), m4);
+
+    let i = Complex::<f64>::i();
+    let sigma_x = Matrix2x2::new([[Complex::from(0.), Complex::from(1.)], [Complex::from(1.), Complex::from(0.)]]);
+    assert!(sigma_x.is_hermitian(0.) && sigma_x.is_unitary(0.));
+    let det = Matrix2x2::from_func(|i, j| Interval::point(if i == j { 3.0 } else { 1.0 })).determinant();
+    assert_eq!(det.sign(), Some(std::cmp::Ordering::Greater));
+    assert_eq!(sigma_x.try_scale(i).unwrap().adjoint(), sigma_x.try_scale(-i).unwrap());
+}
+
+#[test]
+fn prelude_errors() {
+    let err: VectorErr = Vector3D::from_slice(&[1, 2]).unwrap_err();
+    assert_eq!(err.to_string(), "Expected 3 got 2");
+
+    let err: MathErr = Matrix2x2::new([[1, 2], [2, 4]]).try_inverse().unwrap_err().into();
+    assert_eq!(err.to_string(), "Matrix error: Matrix is singular");
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+fn module_paths() {
+    fn takes_scalar<T: yelmath::types::Scalar>(v: T) -> T {
+        v
+    }
+
+    let m: yelmath::matrices::Matrix4x4<i32> = yelmath::matrices::MatrixOps::zero();
+    let v: yelmath::vectors::Vector4D<i32> = yelmath::vectors::Vector::zero();
+    assert_eq!(m.mul_vec(&v), v);
+    assert_eq!(takes_scalar(5i64), 5);
+
+    let g = yelmath::dual::gradient(|v: VectorN<Dual<f64>, 2>| v.dot(&v), &VectorN::new([1., -1.]));
+    assert_eq!(g, VectorN::new([2., -2.]));
+}